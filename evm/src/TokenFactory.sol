@@ -53,6 +53,9 @@ contract TokenFactory is ReentrancyGuard, Ownable, Pausable, OApp {
 
     mapping(address => mapping(address => uint)) private userBoughtAmount;
 
+    /// @notice Solana meme mints that use the Token-2022 program, set by the owner.
+    mapping(bytes32 => bool) public isSolanaToken2022;
+
     uint256 private TOKEN_CREATOR_BONUS;
     uint256 private PLATFORM_FEE;
     address private PLATFORM_TREASURY_ADDRESS;
@@ -74,12 +77,16 @@ contract TokenFactory is ReentrancyGuard, Ownable, Pausable, OApp {
 
     uint8 private constant BUY_TYPE = 1;
     uint8 private constant SELL_TYPE = 2;
+    // set in the msg type when the Solana mint is a Token-2022 mint, the Solana
+    // program can not read the mint before picking the accounts of lz_receive
+    uint8 private constant TOKEN_2022_FLAG = 0x80;
     
     event CreatedMemeToken(address indexed tokenAddress, address indexed creator, string name, string symbol);
     event BoughtMemeToken(address indexed memeTokenAddress, address indexed user, uint tokenQty, uint ethAmount);
     event BoughtCrosschainMemeToken(uint32 indexed srcEid, address indexed memeTokenAddress, address indexed user, uint tokenQty, uint ethAmount);
     event SoldMemeToken(address indexed memeTokenAddress, address indexed user, uint tokenQty, uint ethAmount);
     event SoldCrosschainMemeToken(uint32 indexed srcEid, address indexed memeTokenAddress, address indexed user, uint tokenQty, uint ethAmount);
+    event SolanaTokenProgramSet(bytes32 indexed memeTokenAddress, bool isToken2022);
     
     error TokenNotListed();
     error TokenNotLaunched();
@@ -155,12 +162,21 @@ contract TokenFactory is ReentrancyGuard, Ownable, Pausable, OApp {
         BUY_SELL_FEE_PERCENT = newBuySellFeePercent;
     }
 
+    /// @notice Records whether a Solana meme mint uses the Token-2022 program.
+    /// @dev Only callable by the contract owner, cross-chain messages for the mint carry it.
+    /// @param memeTokenAddress The Solana mint address.
+    /// @param isToken2022 Whether the mint is owned by the Token-2022 program.
+    function setSolanaTokenProgram(bytes32 memeTokenAddress, bool isToken2022) external onlyOwner {
+        isSolanaToken2022[memeTokenAddress] = isToken2022;
+        emit SolanaTokenProgramSet(memeTokenAddress, isToken2022);
+    }
+
     /// @notice Allows users to buy meme tokens using ETH.
     /// @param memeTokenAddress The address of the meme token contract.
     /// @param recipientAddress The recipient address.
     /// @param ethAmount The Eth amount.
     function buyCrosschainMemetoken(uint32 _dstEid, bytes32 memeTokenAddress, bytes32 recipientAddress, uint128 ethAmount) external payable whenNotPaused {
-        bytes memory message = abi.encodePacked(BUY_TYPE, memeTokenAddress, recipientAddress, ethAmount, uint256(0));
+        bytes memory message = abi.encodePacked(_msgType(BUY_TYPE, memeTokenAddress), memeTokenAddress, recipientAddress, ethAmount, uint256(0));
         bytes memory options = OptionsBuilder.newOptions().addExecutorLzReceiveOption(200000, ethAmount);
         _lzSend(
             _dstEid,
@@ -178,7 +194,7 @@ contract TokenFactory is ReentrancyGuard, Ownable, Pausable, OApp {
         bytes32 recipientAddress,
         uint128 ethAmount
     ) external view returns (uint256 nativeFee, uint256 lzTokenFee) {
-        bytes memory message = abi.encodePacked(BUY_TYPE, memeTokenAddress, recipientAddress, ethAmount, uint256(0));
+        bytes memory message = abi.encodePacked(_msgType(BUY_TYPE, memeTokenAddress), memeTokenAddress, recipientAddress, ethAmount, uint256(0));
         bytes memory options = OptionsBuilder.newOptions().addExecutorLzReceiveOption(200000, ethAmount);
         MessagingFee memory fee = _quote(_dstEid, message, options, false);
         return (fee.nativeFee, fee.lzTokenFee);
@@ -189,7 +205,7 @@ contract TokenFactory is ReentrancyGuard, Ownable, Pausable, OApp {
     /// @param recipientAddress The recipient address.
     /// @param tokenQty The Token amount to sell.
     function sellCrosschainMemetoken(uint32 _dstEid, bytes32 memeTokenAddress, bytes32 recipientAddress, uint256 tokenQty) external payable whenNotPaused {
-        bytes memory message = abi.encodePacked(SELL_TYPE, memeTokenAddress, recipientAddress, uint128(0), tokenQty);
+        bytes memory message = abi.encodePacked(_msgType(SELL_TYPE, memeTokenAddress), memeTokenAddress, recipientAddress, uint128(0), tokenQty);
         bytes memory options = OptionsBuilder.newOptions().addExecutorLzReceiveOption(200000, 0);
         _lzSend(
             _dstEid,
@@ -207,13 +223,17 @@ contract TokenFactory is ReentrancyGuard, Ownable, Pausable, OApp {
         bytes32 recipientAddress,
         uint256 tokenQty
     ) external view returns (uint256 nativeFee, uint256 lzTokenFee) {
-        bytes memory message = abi.encodePacked(SELL_TYPE, memeTokenAddress, recipientAddress, uint128(0), tokenQty);
+        bytes memory message = abi.encodePacked(_msgType(SELL_TYPE, memeTokenAddress), memeTokenAddress, recipientAddress, uint128(0), tokenQty);
         bytes memory options = OptionsBuilder.newOptions().addExecutorLzReceiveOption(200000, 0);
         
         MessagingFee memory fee = _quote(_dstEid, message, options, false);
         return (fee.nativeFee, fee.lzTokenFee);
     }
 
+    function _msgType(uint8 msgType, bytes32 memeTokenAddress) private view returns (uint8) {
+        return isSolanaToken2022[memeTokenAddress] ? msgType | TOKEN_2022_FLAG : msgType;
+    }
+
     function _lzReceive(
         Origin calldata origin,
         bytes32,
@@ -241,8 +261,8 @@ contract TokenFactory is ReentrancyGuard, Ownable, Pausable, OApp {
     function decodeMessage(bytes memory message) private pure returns (uint8 msgType, bytes32 memeTokenAddressBytes, bytes32 recipientAddressBytes, uint256 ethAmount, uint256 tokenQty) {
         uint256 index = 0;
 
-        // Decode the msgType (BUY_TYPE or SELL_TYPE) from the first byte
-        msgType = uint8(message[index]);
+        // Decode the msgType (BUY_TYPE or SELL_TYPE) from the first byte, without the Token-2022 flag
+        msgType = uint8(message[index]) & ~TOKEN_2022_FLAG;
         index += 1;
 
         // Use assembly to load 32 bytes from memory starting at position `index`
@@ -9,8 +9,8 @@ library TokenFactoryHelper {
     function decodeMessage(bytes memory message) external pure returns (uint8 msgType, bytes32 memeTokenAddressBytes, bytes32 recipientAddressBytes, uint256 ethAmount, uint256 tokenQty) {
         uint256 index = 0;
 
-        // Decode the msgType (BUY_TYPE or SELL_TYPE) from the first byte
-        msgType = uint8(message[index]);
+        // Decode the msgType (BUY_TYPE or SELL_TYPE) from the first byte, without the Token-2022 flag
+        msgType = uint8(message[index]) & 0x7f;
         index += 1;
 
         // Use assembly to load 32 bytes from memory starting at position `index`
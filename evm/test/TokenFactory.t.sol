@@ -108,6 +108,16 @@ contract TokenFactoryTest is TestHelperOz5 {
         console.log("Increased ETH balance: ", increasedEthBalance);
     }
 
+    function test_SetSolanaTokenProgram() public {
+        bytes32 mint = bytes32(uint256(1));
+        aFactory.setSolanaTokenProgram(mint, true);
+        assertTrue(aFactory.isSolanaToken2022(mint));
+
+        vm.prank(userA);
+        vm.expectRevert();
+        aFactory.setSolanaTokenProgram(mint, false);
+    }
+
     function test_CreateToken() public {
         address tokenAddress = factory.createMemeToken("Test", "TEST", "img://img.png", "hello there", 0, 0, 0, 0, 0, 0, 0, 0);
         Token token = Token(tokenAddress);
@@ -0,0 +1,17 @@
+use crate::*;
+
+// compose msg forwarded to the receiver of an inbound msg:
+// [nonce: 8][src_eid: 4][amount_ld: 8][composeFrom: 32][composeMsg]
+pub fn encode(
+  nonce: u64,
+  src_eid: u32,
+  amount_ld: u64, // msg_codec::compose_amount of the inbound msg
+  compose_msg: &Vec<u8>, // [composeFrom][composeMsg]
+) -> Vec<u8> {
+  let mut encoded = Vec::with_capacity(20 + compose_msg.len()); // 8 + 4 + 8
+  encoded.extend_from_slice(&nonce.to_be_bytes());
+  encoded.extend_from_slice(&src_eid.to_be_bytes());
+  encoded.extend_from_slice(&amount_ld.to_be_bytes());
+  encoded.extend_from_slice(&compose_msg);
+  encoded
+}
@@ -13,7 +13,89 @@ pub enum PumpFunError {
   #[msg("BondingCurve: Input must be greater than zero")]
   InvalidInput,
   #[msg("Slippage Exceed")]
-  SlippageExceed
+  SlippageExceed,
+  #[msg("Bonding curve is not completed")]
+  CurveNotCompleted,
+  #[msg("Token name must not be empty")]
+  EmptyTokenName,
+  #[msg("Token name is too long")]
+  TokenNameTooLong,
+  #[msg("Token name contains invalid characters")]
+  InvalidTokenName,
+  #[msg("Token symbol must not be empty")]
+  EmptyTokenSymbol,
+  #[msg("Token symbol is too long")]
+  TokenSymbolTooLong,
+  #[msg("Token symbol must be ASCII letters and digits")]
+  InvalidTokenSymbol,
+  #[msg("Token uri must not be empty")]
+  EmptyTokenUri,
+  #[msg("Token uri is too long")]
+  TokenUriTooLong,
+  #[msg("Token uri must be an https, ipfs or ar uri")]
+  InvalidTokenUri,
+  #[msg("Bonding curve is already migrated")]
+  CurveAlreadyMigrated,
+  #[msg("Token metadata is not updatable by the creator")]
+  MetadataNotUpdatable,
+  #[msg("Token metadata is locked after graduation")]
+  MetadataLocked,
+  #[msg("Fee exceeds the allowed basis points")]
+  InvalidFeeBps,
+  #[msg("Nothing to claim")]
+  NothingToClaim,
+  #[msg("Math overflow")]
+  MathOverflow,
+  #[msg("Traders can not refer themselves")]
+  SelfReferral,
+  #[msg("Bonding curve accounting is inconsistent")]
+  CurveInvariantViolated,
+  #[msg("Launch protection settings are invalid")]
+  InvalidLaunchProtection,
+  #[msg("Buy exceeds the launch window limit per transaction")]
+  LaunchTxCapExceeded,
+  #[msg("Buy exceeds the launch window limit per wallet")]
+  LaunchWalletCapExceeded,
+  #[msg("Trading has not started yet")]
+  TradingNotStarted,
+  #[msg("Trading has already started")]
+  TradingAlreadyStarted,
+  #[msg("Trading start can only be brought forward")]
+  TradingStartPostponed,
+  #[msg("Presale needs a trading start in the future")]
+  InvalidPresale,
+  #[msg("Token has no presale")]
+  PresaleNotEnabled,
+  #[msg("Presale is closed")]
+  PresaleClosed,
+  #[msg("Wallet is not on the presale allowlist")]
+  InvalidPresaleProof,
+  #[msg("Buy exceeds the presale allocation")]
+  PresaleAllocationExceeded,
+  #[msg("Max holding must be between 0 and 100 %")]
+  InvalidMaxHolding,
+  #[msg("Buy exceeds the max wallet holding")]
+  MaxHoldingExceeded,
+  #[msg("Swap needs two different mints")]
+  SameMint,
+  #[msg("Buy leg accounts do not match its mint")]
+  InvalidBuyLeg,
+  #[msg("Limit order accounts do not match")]
+  InvalidLimitOrder,
+  #[msg("No limit order crossed its trigger price")]
+  OrderNotTriggered,
+  #[msg("Keeper executions wait for the launch window to end")]
+  LaunchWindowOpen,
+  #[msg("Invalid DCA parameters")]
+  InvalidDcaParams,
+  #[msg("DCA interval has not elapsed")]
+  DcaNotDue,
+  #[msg("Price is above the DCA max price")]
+  DcaMaxPriceExceeded,
+  #[msg("DCA vault has no SOL left")]
+  DcaEmpty,
+  #[msg("DCA slice does not cover its fees")]
+  DcaSliceTooSmall,
 }
 
 #[error_code]
@@ -29,4 +111,9 @@ pub enum OftError {
     InvalidOptions,
     InvalidEndpointProgram,
     RateLimitExceeded,
+    InboundDisabled,
+    OutboundDisabled,
+    TokenTransfersDisabled,
+    InvalidEndpointAccounts,
+    InsufficientLiquidity,
 }
\ No newline at end of file
@@ -7,6 +7,25 @@ pub struct CreateTokenEvent {
   pub token_symbol: String,
   pub token_uri: String,
   pub mint: Pubkey,
+  pub initial_buy_sol: u64,
+  pub initial_buy_tokens: u64,
+}
+
+#[event]
+pub struct UpdateTokenMetadataEvent {
+  pub mint: Pubkey,
+  pub creator: Pubkey,
+  pub token_name: String,
+  pub token_symbol: String,
+  pub token_uri: String,
+}
+
+#[event]
+pub struct UpdateTradingStartEvent {
+  pub mint: Pubkey,
+  pub creator: Pubkey,
+  pub previous_start_ts: i64,
+  pub trading_start_ts: i64,
 }
 
 #[event]
@@ -15,6 +34,11 @@ pub struct BuyEvent {
   pub buyer: Pubkey,
   pub sol_input: u64,
   pub token_output: u64,
+  // paid besides sol_input
+  pub creator_fee: u64,
+  pub platform_fee: u64,
+  pub referral_fee: u64,
+  pub referrer: Option<Pubkey>,
 }
 
 #[event]
@@ -23,12 +47,158 @@ pub struct SellEvent {
   pub seller: Pubkey,
   pub sol_output: u64,
   pub token_input: u64,
+  // already taken out of sol_output
+  pub creator_fee: u64,
+  pub platform_fee: u64,
+  pub referral_fee: u64,
+  pub referrer: Option<Pubkey>,
+}
+
+#[event]
+pub struct SwapEvent {
+  pub user: Pubkey,
+  pub mint_in: Pubkey,
+  pub mint_out: Pubkey,
+  pub token_input: u64,
+  pub sol_amount: u64, // sell proceeds before fees
+  pub token_output: u64,
+  pub creator_fee_in: u64,
+  pub creator_fee_out: u64,
+  pub platform_fee: u64,
+  pub referral_fee: u64,
+  pub referrer: Option<Pubkey>,
+}
+
+#[event]
+pub struct CurveCompleted {
+  pub mint: Pubkey,
+  pub creator: Pubkey,
+  pub sol_raised: u64,
+  pub graduation_fee: u64,
+  pub creator_bonus: u64,
+  pub sol_for_liquidity: u64, // left on the curve for migration
+}
+
+#[event]
+pub struct ClaimCreatorFeesEvent {
+  pub creator: Pubkey,
+  pub amount: u64,
+  pub swept: u64, // cross-chain buy fees moved from a curve first
+  pub total_accrued: u64,
+  pub total_claimed: u64,
+}
+
+#[event]
+pub struct RegisterReferrerEvent {
+  pub authority: Pubkey,
+  pub referrer: Pubkey,
+}
+
+#[event]
+pub struct ClaimReferralFeesEvent {
+  pub authority: Pubkey,
+  pub amount: u64,
+  pub total_accrued: u64,
+  pub total_claimed: u64,
+}
+
+#[event]
+pub struct LimitOrderPlacedEvent {
+  pub order: Pubkey,
+  pub mint: Pubkey,
+  pub owner: Pubkey,
+  pub order_id: u64,
+  pub side: OrderSide,
+  pub amount: u64,
+  pub trigger_price: u64,
+  pub tip: u64,
+}
+
+#[event]
+pub struct LimitOrderCancelledEvent {
+  pub order: Pubkey,
+  pub mint: Pubkey,
+  pub owner: Pubkey,
+  pub order_id: u64,
+}
+
+#[event]
+pub struct LimitOrderExecutedEvent {
+  pub order: Pubkey,
+  pub mint: Pubkey,
+  pub owner: Pubkey,
+  pub order_id: u64,
+  pub executor: Pubkey,
+  pub side: OrderSide,
+  pub token_amount: u64,
+  pub sol_amount: u64, // deposited on buys, received after fees on sells
+  pub tip: u64,
+}
+
+#[event]
+pub struct LimitOrderSkippedEvent {
+  pub order: Pubkey,
+  pub mint: Pubkey,
+  pub owner: Pubkey,
+  pub order_id: u64,
+  pub executor: Pubkey,
+}
+
+#[event]
+pub struct CreateDcaEvent {
+  pub dca_vault: Pubkey,
+  pub mint: Pubkey,
+  pub owner: Pubkey,
+  pub deposit: u64,
+  pub amount_per_interval: u64,
+  pub interval_seconds: i64,
+  pub max_price: u64,
+}
+
+#[event]
+pub struct ExecuteDcaEvent {
+  pub dca_vault: Pubkey,
+  pub mint: Pubkey,
+  pub owner: Pubkey,
+  pub keeper: Pubkey,
+  pub sol_amount: u64, // fees included
+  pub token_amount: u64,
+  pub remaining: u64,
+}
+
+#[event]
+pub struct CancelDcaEvent {
+  pub dca_vault: Pubkey,
+  pub mint: Pubkey,
+  pub owner: Pubkey,
+  pub refunded: u64,
+  pub total_spent: u64,
+  pub total_tokens: u64,
+}
+
+#[event]
+pub struct ClaimPlatformFeesEvent {
+  pub destination: Pubkey,
+  pub amount: u64,
+  pub total_platform_fees: u64,
+  pub total_referral_fees: u64,
+  pub total_platform_fees_claimed: u64,
+}
+
+#[event]
+pub struct MigrateBondingCurveEvent {
+  pub mint: Pubkey,
+  pub creator: Pubkey,
+  pub tokens_sold: u64,
+  pub sol_reserve: u64,
+  pub complete: bool,
 }
 
 #[event]
 pub struct WithdrawEvent {
   pub mint: Pubkey,
   pub withdrawer: Pubkey,
+  pub destination: Pubkey,
   pub sol_output: u64,
   pub token_output: u64,
 }
@@ -38,6 +208,7 @@ pub struct OFTSent {
     pub guid: [u8; 32],
     pub dst_eid: u32,
     pub from: Pubkey,
+    pub token_source: Pubkey,
     pub amount_sent_ld: u64,
     pub amount_received_ld: u64,
 }
@@ -49,3 +220,48 @@ pub struct OFTReceived {
     pub to: Pubkey,
     pub amount_received_ld: u64,
 }
+
+#[event]
+pub struct PeerSet {
+    pub eid: u32,
+    pub peer: [u8; 32],
+    pub inbound_enabled: bool,
+    pub outbound_enabled: bool,
+    pub token_transfers_enabled: bool,
+}
+
+#[event]
+pub struct PeerRemoved {
+    pub eid: u32,
+    pub peer: [u8; 32],
+}
+
+#[event]
+pub struct PeerSolVaultFunded {
+    pub eid: u32,
+    pub funder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PeerSolVaultSpent {
+    pub eid: u32,
+    pub guid: [u8; 32],
+    pub mint: Pubkey,
+    pub executor: Pubkey,
+    pub sol_amount: u64,
+    pub rent_reimbursed: u64,
+}
+
+#[event]
+pub struct PeerSolVaultReconciled {
+    pub eid: u32,
+    pub destination: Pubkey,
+    pub withdrawn: u64,
+    pub remote_settled: u64,
+    pub total_funded: u64,
+    pub total_spent: u64,
+    pub total_rent_reimbursed: u64,
+    pub total_withdrawn: u64,
+    pub balance: u64,
+}
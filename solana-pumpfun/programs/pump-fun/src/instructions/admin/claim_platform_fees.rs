@@ -0,0 +1,66 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ClaimPlatformFees<'info> {
+    /// Admin address
+  #[account(
+    address = global_config.admin
+  )]
+  pub authority: Signer<'info>,
+
+  #[account(
+    mut,
+    seeds = [
+      CONFIG_SEED,
+    ],
+    bump = global_config.bump
+  )]
+  pub global_config: Box<Account<'info, GlobalConfig>>,
+
+  #[account(
+    mut,
+    seeds = [
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump = platform_fee_vault.bump
+  )]
+  pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+  /// CHECK: any account chosen by the admin to receive the fees
+  #[account(mut)]
+  pub destination: UncheckedAccount<'info>,
+}
+
+impl ClaimPlatformFees<'_> {
+  pub fn apply(ctx: &mut Context<ClaimPlatformFees>, params: &ClaimPlatformFeesParams) -> Result<()> {
+    let claimable = ctx.accounts.global_config.claimable_platform_fees();
+    let amount = params.amount.unwrap_or(claimable);
+    require!(amount > 0, PumpFunError::NothingToClaim);
+    require!(amount <= claimable, PumpFunError::InvalidInput);
+
+    transfer_sol_from_vault_to_user(
+      ctx.accounts.platform_fee_vault.to_account_info(),
+      ctx.accounts.destination.to_account_info(),
+      amount,
+    )?;
+    let config = &mut ctx.accounts.global_config;
+    config.total_platform_fees_claimed = config
+        .total_platform_fees_claimed
+        .checked_add(amount)
+        .ok_or(PumpFunError::MathOverflow)?;
+
+    emit!(ClaimPlatformFeesEvent {
+      destination: ctx.accounts.destination.key(),
+      amount,
+      total_platform_fees: config.total_platform_fees,
+      total_referral_fees: config.total_referral_fees,
+      total_platform_fees_claimed: config.total_platform_fees_claimed,
+    });
+    Ok(())
+  }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimPlatformFeesParams {
+  pub amount: Option<u64>, // everything claimable when None
+}
@@ -20,6 +20,17 @@ pub struct CreateGlobalConfig<'info> {
   )]
   pub global_config: Account<'info, GlobalConfig>,
 
+  #[account(
+    init,
+    seeds=[
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump,
+    payer = payer,
+    space = 8 + PlatformFeeVault::INIT_SPACE
+  )]
+  pub platform_fee_vault: Account<'info, PlatformFeeVault>,
+
   pub system_program: Program<'info, System>,
 }
 impl CreateGlobalConfig<'_> {
@@ -27,10 +38,18 @@ impl CreateGlobalConfig<'_> {
     let config = ctx.accounts.global_config.deref_mut();
     require!(!config.is_initialized, PumpFunError::AlreadyInitialized);
     config.admin = ctx.accounts.payer.key();
+    config.withdraw_destination = params.withdraw_destination;
     config.fee_recipient = params.fee_recipient;
     config.fee_rate = params.fee_rate; // 1: 0.001 %
+    config.creator_fee_bps = params.creator_fee_bps;
+    config.max_creator_fee_bps = params.max_creator_fee_bps;
+    config.referral_fee_bps = params.referral_fee_bps;
+    config.graduation_fee = params.graduation_fee;
+    config.creator_completion_bonus = params.creator_completion_bonus;
+    config.validate_fees()?;
     config.bump = ctx.bumps.global_config;
     config.is_initialized = true;
+    ctx.accounts.platform_fee_vault.bump = ctx.bumps.platform_fee_vault;
     Ok(())
   }
 }
@@ -39,5 +58,11 @@ impl CreateGlobalConfig<'_> {
 pub struct CreateGlobalConfigParams {
   pub fee_recipient: Pubkey,
   pub admin: Pubkey,
+  pub withdraw_destination: Pubkey,
   pub fee_rate: u32,
+  pub creator_fee_bps: u16, // 100: 1 %
+  pub max_creator_fee_bps: u16,
+  pub referral_fee_bps: u16, // share of the platform fee
+  pub graduation_fee: u64, // lamports
+  pub creator_completion_bonus: u64, // lamports
 }
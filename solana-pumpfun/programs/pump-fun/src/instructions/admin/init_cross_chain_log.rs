@@ -0,0 +1,40 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: InitCrossChainLogParams)]
+pub struct InitCrossChainLog<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CrossChainLog::INIT_SPACE,
+        seeds = [CROSS_CHAIN_LOG_SEED, &oapp_config.key().to_bytes(), &params.eid.to_be_bytes()],
+        bump
+    )]
+    pub cross_chain_log: Box<Account<'info, CrossChainLog>>,
+    #[account(
+        seeds = [OAPP_SEED],
+        bump = oapp_config.bump,
+        has_one = admin @OftError::Unauthorized
+    )]
+    pub oapp_config: Box<Account<'info, OAppConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitCrossChainLog<'_> {
+    pub fn apply(ctx: &mut Context<InitCrossChainLog>, params: &InitCrossChainLogParams) -> Result<()> {
+        let log = &mut ctx.accounts.cross_chain_log;
+        log.eid = params.eid;
+        log.bump = ctx.bumps.cross_chain_log;
+        log.head = 0;
+        log.total = 0;
+        log.entries = Vec::new();
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct InitCrossChainLogParams {
+    pub eid: u32,
+}
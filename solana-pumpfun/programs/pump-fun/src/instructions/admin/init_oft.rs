@@ -0,0 +1,75 @@
+use crate::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Turns a graduated meme token into an OFT so it can be bridged to the evm Token deployments.
+#[derive(Accounts)]
+pub struct InitOft<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [OAPP_SEED],
+        bump = oapp_config.bump,
+        has_one = admin @OftError::Unauthorized
+    )]
+    pub oapp_config: Box<Account<'info, OAppConfig>>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + OftStore::INIT_SPACE,
+        seeds = [OFT_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub oft_store: Box<Account<'info, OftStore>>,
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = token_mint,
+        associated_token::authority = oft_store,
+        token::token_program = token_program,
+    )]
+    pub token_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mint::token_program = token_program)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK
+    #[account(
+        seeds = [
+            BONDING_CURVE_SEED,
+            token_mint.key().as_ref()
+        ],
+        bump,
+    )]
+    pub bonding_curve: UncheckedAccount<'info>,
+    #[account(
+        associated_token::mint = token_mint,
+        associated_token::authority = bonding_curve,
+        token::token_program = token_program,
+    )]
+    pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitOft<'_> {
+    pub fn apply(ctx: &mut Context<InitOft>) -> Result<()> {
+        // only graduated tokens can leave the bonding curve
+        require!(
+            ctx.accounts.associted_bonding_curve.amount <= INIT_SUPPLY,
+            PumpFunError::CurveNotCompleted
+        );
+
+        let decimals = ctx.accounts.token_mint.decimals;
+        require!(decimals >= SHARED_DECIMALS, OftError::InvalidDecimals);
+
+        let oft_store = &mut ctx.accounts.oft_store;
+        oft_store.ld2sd_rate = 10u64.pow((decimals - SHARED_DECIMALS) as u32);
+        oft_store.token_mint = ctx.accounts.token_mint.key();
+        oft_store.token_escrow = ctx.accounts.token_escrow.key();
+        oft_store.bump = ctx.bumps.oft_store;
+        oft_store.tvl_ld = 0;
+        Ok(())
+    }
+}
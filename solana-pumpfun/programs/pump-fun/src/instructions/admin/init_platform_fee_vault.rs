@@ -0,0 +1,41 @@
+use crate::*;
+
+/// Creates the platform fee vault for configs created before it existed,
+/// new configs get it from create_global_config.
+#[derive(Accounts)]
+pub struct InitPlatformFeeVault<'info> {
+    /// Admin address
+  #[account(
+    mut,
+    address = global_config.admin
+  )]
+  pub authority: Signer<'info>,
+
+  #[account(
+    seeds=[
+      CONFIG_SEED,
+    ],
+    bump = global_config.bump
+  )]
+  pub global_config: Account<'info, GlobalConfig>,
+
+  #[account(
+    init,
+    seeds=[
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump,
+    payer = authority,
+    space = 8 + PlatformFeeVault::INIT_SPACE
+  )]
+  pub platform_fee_vault: Account<'info, PlatformFeeVault>,
+
+  pub system_program: Program<'info, System>,
+}
+
+impl InitPlatformFeeVault<'_> {
+  pub fn apply(ctx: &mut Context<InitPlatformFeeVault>) -> Result<()> {
+    ctx.accounts.platform_fee_vault.bump = ctx.bumps.platform_fee_vault;
+    Ok(())
+  }
+}
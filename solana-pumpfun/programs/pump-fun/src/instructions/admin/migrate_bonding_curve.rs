@@ -0,0 +1,153 @@
+use crate::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::{
+    token_2022::spl_token_2022::instruction::AuthorityType,
+    token_interface::{set_authority, Mint, SetAuthority, TokenAccount, TokenInterface},
+};
+
+/// Turns a curve created before curve state existed (a 0-space PDA holding only SOL)
+/// into a `BondingCurve`, rebuilding its accounting from the vault and its lamports.
+#[derive(Accounts)]
+#[instruction(params: MigrateBondingCurveParams)]
+pub struct MigrateBondingCurve<'info> {
+    /// Admin address
+  #[account(
+    mut,
+    address = global_config.admin
+  )]
+  pub authority: Signer<'info>,
+
+  #[account(
+    seeds = [
+      CONFIG_SEED,
+    ],
+    bump = global_config.bump
+  )]
+  pub global_config: Box<Account<'info, GlobalConfig>>,
+
+  #[account(
+    mut,
+    mint::token_program = token_program,
+  )]
+  pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+  /// CHECK: legacy curve, checked and initialized in apply
+  #[account(
+    mut,
+    seeds = [
+      BONDING_CURVE_SEED,
+      token_mint.key().as_ref()
+    ],
+    bump,
+  )]
+  pub bonding_curve: UncheckedAccount<'info>,
+
+  #[account(
+    init_if_needed,
+    seeds = [
+      CREATOR_VAULT_SEED,
+      params.creator.as_ref()
+    ],
+    payer = authority,
+    space = 8 + CreatorVault::INIT_SPACE,
+    bump,
+  )]
+  pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+  #[account(
+    associated_token::mint = token_mint,
+    associated_token::authority = bonding_curve,
+    token::token_program = token_program,
+  )]
+  pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  pub token_program: Interface<'info, TokenInterface>,
+  pub system_program: Program<'info, System>,
+}
+
+impl MigrateBondingCurve<'_> {
+  pub fn apply(ctx: &mut Context<MigrateBondingCurve>, params: &MigrateBondingCurveParams) -> Result<()> {
+    let info = ctx.accounts.bonding_curve.to_account_info();
+    require!(
+        info.owner == &crate::ID && info.data_is_empty(),
+        PumpFunError::CurveAlreadyMigrated
+    );
+    let token_mint = ctx.accounts.token_mint.key();
+    let bump = ctx.bumps.bonding_curve;
+    let signer_seeds: &[&[&[u8]]] = &[&[BONDING_CURVE_SEED, token_mint.as_ref(), &[bump]]];
+
+    // everything above the 0-space rent is SOL raised by the curve
+    let rent = Rent::get()?;
+    let sol_reserve = info.lamports().saturating_sub(rent.minimum_balance(0));
+    let space = 8 + BondingCurve::INIT_SPACE;
+    transfer_sol(
+        ctx.accounts.authority.to_account_info(),
+        info.clone(),
+        rent.minimum_balance(space) - rent.minimum_balance(0),
+    )?;
+    info.realloc(space, true)?;
+
+    // legacy mints kept bonding_curve as mint authority, drop it like create_token does
+    match ctx.accounts.token_mint.mint_authority {
+        COption::Some(authority) if authority == info.key() => set_authority(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: info.clone(),
+                    account_or_mint: ctx.accounts.token_mint.to_account_info(),
+                },
+            )
+            .with_signer(signer_seeds),
+            AuthorityType::MintTokens,
+            None,
+        )?,
+        COption::Some(_) => return err!(PumpFunError::InvalidInput),
+        COption::None => {}
+    }
+
+    let vault_amount = ctx.accounts.associted_bonding_curve.amount;
+    let curve = BondingCurve {
+        token_mint,
+        creator: params.creator,
+        token_program: ctx.accounts.token_program.key(),
+        bump,
+        mint_authority_revoked: true,
+        freeze_authority_revoked: ctx.accounts.token_mint.freeze_authority.is_none(),
+        // the metaplex update authority stays with bonding_curve
+        metadata_authority: MetadataAuthority::Creator,
+        creator_fee_bps: ctx.accounts.global_config.creator_fee_bps,
+        creator_fees_unswept: 0,
+        complete: BondingCurve::completes(vault_amount),
+        tokens_sold: MAX_SUPPLY - vault_amount,
+        sol_reserve,
+        trading_start_ts: 0,
+        launch_slot: Clock::get()?.slot,
+        launch_protection: None,
+        presale_merkle_root: None,
+        max_holding_bps: None,
+        tokens_withdrawn: 0,
+    };
+    curve.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    let creator_vault = &mut ctx.accounts.creator_vault;
+    if creator_vault.creator == Pubkey::default() {
+      creator_vault.creator = params.creator;
+      creator_vault.bump = ctx.bumps.creator_vault;
+    }
+
+    emit!(MigrateBondingCurveEvent {
+        mint: token_mint,
+        creator: params.creator,
+        tokens_sold: curve.tokens_sold,
+        sol_reserve,
+        complete: curve.complete,
+    });
+    Ok(())
+  }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateBondingCurveParams {
+  // legacy curves did not record their creator
+  pub creator: Pubkey,
+}
@@ -0,0 +1,88 @@
+use crate::*;
+
+// GlobalConfig as first deployed, before the fee and withdraw fields were appended
+#[derive(AnchorDeserialize)]
+struct LegacyGlobalConfig {
+    admin: Pubkey,
+    fee_recipient: Pubkey,
+    fee_rate: u32,
+    bump: u8,
+    is_initialized: bool,
+}
+
+const LEGACY_GLOBAL_CONFIG_SPACE: usize = 32 + 32 + 4 + 1 + 1;
+
+/// Grows a config created with the original layout and fills in the appended fields.
+#[derive(Accounts)]
+pub struct MigrateGlobalConfig<'info> {
+  /// Admin address, checked against the legacy config in apply
+  #[account(mut)]
+  pub authority: Signer<'info>,
+
+  /// CHECK: legacy layout, does not deserialize as GlobalConfig until migrated
+  #[account(
+    mut,
+    seeds=[
+      CONFIG_SEED,
+    ],
+    bump,
+    owner = crate::ID,
+  )]
+  pub global_config: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+impl MigrateGlobalConfig<'_> {
+  pub fn apply(ctx: &mut Context<MigrateGlobalConfig>, params: &MigrateGlobalConfigParams) -> Result<()> {
+    let info = ctx.accounts.global_config.to_account_info();
+    require!(
+        info.data_len() == 8 + LEGACY_GLOBAL_CONFIG_SPACE,
+        PumpFunError::AlreadyInitialized
+    );
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(data[..8] == GlobalConfig::DISCRIMINATOR, ErrorCode::AccountDiscriminatorMismatch);
+        LegacyGlobalConfig::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(legacy.admin, ctx.accounts.authority.key(), ErrorCode::ConstraintAddress);
+
+    let space = 8 + GlobalConfig::INIT_SPACE;
+    let rent = Rent::get()?;
+    transfer_sol(
+        ctx.accounts.authority.to_account_info(),
+        info.clone(),
+        rent.minimum_balance(space).saturating_sub(info.lamports()),
+    )?;
+    info.realloc(space, true)?;
+
+    let config = GlobalConfig {
+        admin: legacy.admin,
+        fee_recipient: legacy.fee_recipient,
+        fee_rate: legacy.fee_rate,
+        bump: legacy.bump,
+        is_initialized: legacy.is_initialized,
+        creator_fee_bps: params.creator_fee_bps,
+        max_creator_fee_bps: params.max_creator_fee_bps,
+        referral_fee_bps: params.referral_fee_bps,
+        graduation_fee: params.graduation_fee,
+        creator_completion_bonus: params.creator_completion_bonus,
+        total_platform_fees: 0,
+        total_referral_fees: 0,
+        total_platform_fees_claimed: 0,
+        withdraw_destination: params.withdraw_destination,
+    };
+    config.validate_fees()?;
+    config.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+  }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateGlobalConfigParams {
+  pub withdraw_destination: Pubkey,
+  pub creator_fee_bps: u16, // 100: 1 %
+  pub max_creator_fee_bps: u16,
+  pub referral_fee_bps: u16, // share of the platform fee
+  pub graduation_fee: u64, // lamports
+  pub creator_completion_bonus: u64, // lamports
+}
@@ -2,8 +2,22 @@ pub mod create_global_config;
 pub mod update_global_config;
 pub mod withdraw;
 pub mod transfer_oapp_admin;
+pub mod init_oft;
+pub mod reconcile_peer_sol_vault;
+pub mod init_cross_chain_log;
+pub mod claim_platform_fees;
+pub mod migrate_bonding_curve;
+pub mod migrate_global_config;
+pub mod init_platform_fee_vault;
 
 pub use create_global_config::*;
 pub use update_global_config::*;
 pub use withdraw::*;
-pub use transfer_oapp_admin::*;
\ No newline at end of file
+pub use transfer_oapp_admin::*;
+pub use init_oft::*;
+pub use reconcile_peer_sol_vault::*;
+pub use init_cross_chain_log::*;
+pub use claim_platform_fees::*;
+pub use migrate_bonding_curve::*;
+pub use migrate_global_config::*;
+pub use init_platform_fee_vault::*;
@@ -0,0 +1,68 @@
+use crate::*;
+
+/// Records what the remote chain has collected for this peer and
+/// optionally takes surplus SOL out of the vault.
+#[derive(Accounts)]
+#[instruction(params: ReconcilePeerSolVaultParams)]
+pub struct ReconcilePeerSolVault<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PEER_SOL_VAULT_SEED, &oapp_config.key().to_bytes(), &params.src_eid.to_be_bytes()],
+        bump = peer_sol_vault.bump
+    )]
+    pub peer_sol_vault: Box<Account<'info, PeerSolVault>>,
+    /// CHECK: any account chosen by the admin to receive the withdrawn SOL
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    #[account(
+        seeds = [OAPP_SEED],
+        bump = oapp_config.bump,
+        has_one = admin @OftError::Unauthorized
+    )]
+    pub oapp_config: Box<Account<'info, OAppConfig>>,
+}
+
+impl ReconcilePeerSolVault<'_> {
+    pub fn apply(
+        ctx: &mut Context<ReconcilePeerSolVault>,
+        params: &ReconcilePeerSolVaultParams,
+    ) -> Result<()> {
+        if params.withdraw_amount > 0 {
+            let available = ctx.accounts.peer_sol_vault.available(
+                &ctx.accounts.peer_sol_vault.to_account_info(),
+                &Rent::get()?,
+            );
+            require!(params.withdraw_amount <= available, OftError::InsufficientLiquidity);
+            transfer_sol_from_vault_to_user(
+                ctx.accounts.peer_sol_vault.to_account_info(),
+                ctx.accounts.destination.to_account_info(),
+                params.withdraw_amount,
+            )?;
+        }
+
+        let vault = &mut ctx.accounts.peer_sol_vault;
+        vault.record_withdrawn(params.withdraw_amount)?;
+        vault.remote_settled = params.remote_settled;
+
+        emit!(PeerSolVaultReconciled {
+            eid: params.src_eid,
+            destination: ctx.accounts.destination.key(),
+            withdrawn: params.withdraw_amount,
+            remote_settled: vault.remote_settled,
+            total_funded: vault.total_funded,
+            total_spent: vault.total_spent,
+            total_rent_reimbursed: vault.total_rent_reimbursed,
+            total_withdrawn: vault.total_withdrawn,
+            balance: vault.to_account_info().lamports(),
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ReconcilePeerSolVaultParams {
+    pub src_eid: u32,
+    pub remote_settled: u64,
+    pub withdraw_amount: u64,
+}
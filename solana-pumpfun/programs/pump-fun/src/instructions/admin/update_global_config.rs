@@ -11,6 +11,7 @@ pub struct UpdateGlobalConfig<'info> {
   pub authority: Signer<'info>,
 
   #[account(
+    mut,
     seeds=[
       CONFIG_SEED,
     ],
@@ -25,12 +26,32 @@ impl UpdateGlobalConfig<'_> {
     if params.admin.is_some() {
       config.admin = params.admin.unwrap();
     }
+    if params.withdraw_destination.is_some() {
+      config.withdraw_destination = params.withdraw_destination.unwrap();
+    }
     if params.fee_recipient.is_some() {
       config.fee_recipient = params.fee_recipient.unwrap();
     }
     if params.fee_rate.is_some() {
       config.fee_rate = params.fee_rate.unwrap();
     }
+    // only applies to tokens created afterwards, existing curves keep their rate
+    if params.creator_fee_bps.is_some() {
+      config.creator_fee_bps = params.creator_fee_bps.unwrap();
+    }
+    if params.max_creator_fee_bps.is_some() {
+      config.max_creator_fee_bps = params.max_creator_fee_bps.unwrap();
+    }
+    if params.referral_fee_bps.is_some() {
+      config.referral_fee_bps = params.referral_fee_bps.unwrap();
+    }
+    if params.graduation_fee.is_some() {
+      config.graduation_fee = params.graduation_fee.unwrap();
+    }
+    if params.creator_completion_bonus.is_some() {
+      config.creator_completion_bonus = params.creator_completion_bonus.unwrap();
+    }
+    config.validate_fees()?;
     Ok(())
   }
 }
@@ -38,7 +59,13 @@ impl UpdateGlobalConfig<'_> {
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateGlobalConfigParams {
   pub admin: Option<Pubkey>,
+  pub withdraw_destination: Option<Pubkey>,
   pub fee_recipient: Option<Pubkey>,
   pub fee_rate: Option<u32>,
+  pub creator_fee_bps: Option<u16>,
+  pub max_creator_fee_bps: Option<u16>,
+  pub referral_fee_bps: Option<u16>,
+  pub graduation_fee: Option<u64>,
+  pub creator_completion_bonus: Option<u64>,
 }
 
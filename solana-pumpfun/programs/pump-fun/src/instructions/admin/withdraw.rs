@@ -1,10 +1,10 @@
 use crate::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::Token,
-    token_interface::{Mint, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
+/// Moves the migration residue of a completed curve to the configured withdraw destination.
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
     /// Admin address
@@ -28,16 +28,26 @@ pub struct Withdraw<'info> {
   )]
   pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// CHECK
   #[account(
       mut,
       seeds = [
       BONDING_CURVE_SEED,
       token_mint.key().as_ref()
       ],
-      bump,
+      bump = bonding_curve.bump,
+      constraint = bonding_curve.complete @PumpFunError::CurveNotCompleted,
   )]
-    pub bonding_curve: UncheckedAccount<'info>,
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+      mut,
+      seeds = [
+      CREATOR_VAULT_SEED,
+      bonding_curve.creator.as_ref()
+      ],
+      bump = creator_vault.bump,
+  )]
+    pub creator_vault: Box<Account<'info, CreatorVault>>,
 
     #[account(
     mut,
@@ -47,59 +57,77 @@ pub struct Withdraw<'info> {
   )]
     pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// CHECK: receives the SOL residue
     #[account(
-    mut,
+      mut,
+      address = global_config.withdraw_destination
+  )]
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+    init_if_needed,
     associated_token::mint = token_mint,
-    associated_token::authority = authority,
+    associated_token::authority = destination,
     token::token_program = token_program,
+    payer = authority,
   )]
-    pub associted_user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 impl Withdraw<'_> {
   pub fn apply(ctx: &mut Context<Withdraw>) -> Result<()> {
+    // creator fees still held on the curve are not part of the raise
+    CreatorVault::sweep(&mut ctx.accounts.creator_vault, &mut ctx.accounts.bonding_curve)?;
 
-    let sol_amount = ctx.accounts.bonding_curve.to_account_info().lamports();
-      //check sol_amount
-      // assert!(
-      //     sol_amount >= WITHDRAWABLE_MIN_SOL_AMOUNT,
-      //     "Insufficient amount to withdraw"
-      // );
+    // the curve keeps its state, only lamports above the rent-exempt minimum leave
+    let bonding_curve_info = ctx.accounts.bonding_curve.to_account_info();
+    let sol_amount = bonding_curve_info
+      .lamports()
+      .saturating_sub(Rent::get()?.minimum_balance(bonding_curve_info.data_len()));
 
     let token_amount = ctx.accounts.associted_bonding_curve.amount;
-    //transfer token from vault to user
+    require!(sol_amount > 0 || token_amount > 0, PumpFunError::NothingToClaim);
+
+    //transfer token from vault to destination
     let token_mint = ctx.accounts.token_mint.key();
     let vault_seeds = &[
         BONDING_CURVE_SEED,
         token_mint.as_ref(),
-        &[ctx.bumps.bonding_curve],
+        &[ctx.accounts.bonding_curve.bump],
     ];
     let vault_signer_seeds = &[&vault_seeds[..]];
     let decimals = ctx.accounts.token_mint.decimals;
-    transfer_token_from_vault_to_user(
-        ctx.accounts.bonding_curve.to_account_info(),
-        ctx.accounts.associted_bonding_curve.to_account_info(),
-        ctx.accounts.associted_user_token_account.to_account_info(),
-        ctx.accounts.token_mint.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
-        token_amount,
-        decimals,
-        vault_signer_seeds,
-    )?;
+    if token_amount > 0 {
+      transfer_token_from_vault_to_user(
+          ctx.accounts.bonding_curve.to_account_info(),
+          ctx.accounts.associted_bonding_curve.to_account_info(),
+          ctx.accounts.destination_token_account.to_account_info(),
+          ctx.accounts.token_mint.to_account_info(),
+          ctx.accounts.token_program.to_account_info(),
+          token_amount,
+          decimals,
+          vault_signer_seeds,
+      )?;
+    }
 
-    //transfer sol from vault to user
+    //transfer sol from vault to destination
     transfer_sol_from_vault_to_user(
         ctx.accounts.bonding_curve.to_account_info(),
-        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.destination.to_account_info(),
         sol_amount,
     )?;
+    // the migrated residue leaves the curve accounting
+    let curve = &mut ctx.accounts.bonding_curve;
+    curve.tokens_withdrawn = curve.tokens_withdrawn.checked_add(token_amount).ok_or(PumpFunError::MathOverflow)?;
+    curve.sol_reserve = curve.sol_reserve.saturating_sub(sol_amount);
 
     emit!(WithdrawEvent {
         mint: ctx.accounts.token_mint.key(),
         withdrawer: ctx.accounts.authority.key(),
+        destination: ctx.accounts.destination.key(),
         sol_output: sol_amount,
         token_output: token_amount,
     });
@@ -107,4 +135,3 @@ impl Withdraw<'_> {
     Ok(())
   }
 }
-
@@ -2,8 +2,7 @@ use crate::*;
 
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::Token,
-    token_interface::{Mint, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 #[derive(Accounts)]
@@ -12,6 +11,7 @@ pub struct Buy<'info> {
   pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
   #[account(
+    mut,
     seeds = [
       CONFIG_SEED,
     ],
@@ -19,16 +19,46 @@ pub struct Buy<'info> {
   )]
   pub global_config: Box<Account<'info, GlobalConfig>>,
 
-    /// CHECK
   #[account(
     mut,
     seeds = [
       BONDING_CURVE_SEED,
       token_mint.key().as_ref()
     ],
-    bump,
+    bump = bonding_curve.bump,
+  )]
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    mut,
+    seeds = [
+      CREATOR_VAULT_SEED,
+      bonding_curve.creator.as_ref()
+    ],
+    bump = creator_vault.bump,
+  )]
+  pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+  #[account(
+    mut,
+    seeds = [
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump = platform_fee_vault.bump
+  )]
+  pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+  // optional, takes referral_fee_bps of the platform fee
+  #[account(
+    mut,
+    seeds = [
+      REFERRER_SEED,
+      referrer.authority.as_ref()
+    ],
+    bump = referrer.bump,
+    constraint = referrer.authority != user.key() @PumpFunError::SelfReferral,
   )]
-  pub bonding_curve: UncheckedAccount<'info>,
+  pub referrer: Option<Box<Account<'info, Referrer>>>,
 
   #[account(
     mut,
@@ -38,6 +68,18 @@ pub struct Buy<'info> {
   )]
   pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
 
+  /// CHECK: per-wallet launch window tally, created in apply while the window is open
+  #[account(
+    mut,
+    seeds = [
+      LAUNCH_ALLOCATION_SEED,
+      token_mint.key().as_ref(),
+      user.key().as_ref()
+    ],
+    bump,
+  )]
+  pub launch_allocation: UncheckedAccount<'info>,
+
   #[account(
     init_if_needed,
     associated_token::mint = token_mint,
@@ -50,30 +92,46 @@ pub struct Buy<'info> {
   #[account(mut)]
     pub user: Signer<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 impl Buy<'_> {
-  pub fn apply(ctx: &mut Context<Buy>, amount: u64, max_sol_cost: u64) -> Result<()> {
+  pub fn apply(ctx: &mut Context<Buy>, amount: u64, max_sol_cost: u64, allow_partial: bool) -> Result<()> {
     let decimals = ctx.accounts.token_mint.decimals;
+    ctx.accounts.bonding_curve.check_trading_open(&Clock::get()?)?;
+
+    // checked against the funding goal and the available supply
+    let vault_amount = ctx.accounts.associted_bonding_curve.amount;
+    // a partial fill stops at the remaining supply and completes the curve
+    let filled = if allow_partial {
+        amount.min(available_supply(vault_amount)?)
+    } else {
+        amount
+    };
+    let required_lamports = buy_cost(vault_amount, filled, decimals)?;
+    let fees = Self::fees(ctx, required_lamports);
+
+    ctx.accounts.bonding_curve.check_holding(
+        ctx.accounts.associted_user_token_account.amount
+            .checked_add(filled)
+            .ok_or(PumpFunError::MathOverflow)?,
+    )?;
 
-    // check to ensure funding goal is not met
-    require!(
-        ctx.accounts.associted_bonding_curve.amount > INIT_SUPPLY,
-        PumpFunError::AlreadyRaised
-    );
-
-    let available_qty =
-        ctx.accounts.associted_bonding_curve.amount - INIT_SUPPLY;
-    require!(amount <= available_qty, PumpFunError::NotEnoughSuppply);
-
-    let current_supply =
-        MAX_SUPPLY - ctx.accounts.associted_bonding_curve.amount;
-    let required_lamports = calculate_cost(current_supply, amount, decimals);
+    // launch window caps, the creator's initial buy in create_token is exempt
+    LaunchAllocation::check_buy(
+        &ctx.accounts.bonding_curve,
+        &ctx.accounts.launch_allocation.to_account_info(),
+        ctx.bumps.launch_allocation,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        filled,
+    )?;
 
+    // max_sol_cost is for the full amount, a partial fill must keep its price
+    let total_cost = required_lamports.checked_add(fees.total()).ok_or(PumpFunError::MathOverflow)?;
     require!(
-        max_sol_cost >= required_lamports,
+        max_sol_cost >= total_cost && price_at_least(filled, total_cost, amount, max_sol_cost),
         PumpFunError::InvalidSolAmount
     );
 
@@ -84,13 +142,21 @@ impl Buy<'_> {
         required_lamports,
     )?;
     //transfer fee
+    pay_trade_fees(
+        &ctx.accounts.user.to_account_info(),
+        &fees,
+        Some(&mut *ctx.accounts.creator_vault),
+        &ctx.accounts.platform_fee_vault.to_account_info(),
+        ctx.accounts.referrer.as_deref_mut(),
+        &mut ctx.accounts.global_config,
+    )?;
 
     //transfer token from vault to user
     let token_mint = ctx.accounts.token_mint.key();
     let vault_seeds = &[
         BONDING_CURVE_SEED,
         token_mint.as_ref(),
-        &[ctx.bumps.bonding_curve],
+        &[ctx.accounts.bonding_curve.bump],
     ];
     let vault_signer_seeds = &[&vault_seeds[..]];
 
@@ -100,16 +166,45 @@ impl Buy<'_> {
         ctx.accounts.associted_user_token_account.to_account_info(),
         ctx.accounts.token_mint.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
-        amount,
+        filled,
         decimals,
         vault_signer_seeds,
     )?;
+    ctx.accounts.bonding_curve.record_buy(filled, required_lamports)?;
     emit!(BuyEvent {
         mint: ctx.accounts.token_mint.key(),
-        token_output: amount,
+        token_output: filled,
         sol_input: required_lamports,
-        buyer: ctx.accounts.user.key()
+        buyer: ctx.accounts.user.key(),
+        creator_fee: fees.creator_fee,
+        platform_fee: fees.platform_fee,
+        referral_fee: fees.referral_fee,
+        referrer: ctx.accounts.referrer.as_ref().map(|referrer| referrer.authority),
     });
-    Ok(())
+
+    if BondingCurve::completes(vault_amount - filled) {
+        BondingCurve::complete(
+            &mut ctx.accounts.bonding_curve,
+            &mut ctx.accounts.global_config,
+            ctx.accounts.platform_fee_vault.to_account_info(),
+            Some(ctx.accounts.creator_vault.as_mut()),
+        )?;
+    }
+
+    ctx.accounts.associted_bonding_curve.reload()?;
+    ctx.accounts.bonding_curve.assert_invariants(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        ctx.accounts.associted_bonding_curve.amount,
+        decimals,
+    )
+  }
+
+  pub fn fees(ctx: &Context<Buy>, sol_amount: u64) -> TradeFees {
+    TradeFees::new(
+      sol_amount,
+      ctx.accounts.bonding_curve.creator_fee_bps,
+      ctx.accounts.global_config.fee_rate,
+      ctx.accounts.referrer.as_ref().map(|_| ctx.accounts.global_config.referral_fee_bps),
+    )
   }
 }
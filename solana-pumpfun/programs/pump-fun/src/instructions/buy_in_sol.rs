@@ -2,8 +2,7 @@ use crate::*;
 
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::Token,
-    token_interface::{Mint, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 #[derive(Accounts)]
@@ -11,6 +10,7 @@ pub struct BuyInSol<'info> {
   pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
   #[account(
+    mut,
     seeds = [
       CONFIG_SEED,
     ],
@@ -18,16 +18,46 @@ pub struct BuyInSol<'info> {
   )]
   pub global_config: Box<Account<'info, GlobalConfig>>,
 
-    /// CHECK
   #[account(
     mut,
     seeds = [
       BONDING_CURVE_SEED,
       token_mint.key().as_ref()
     ],
-    bump,
+    bump = bonding_curve.bump,
+  )]
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    mut,
+    seeds = [
+      CREATOR_VAULT_SEED,
+      bonding_curve.creator.as_ref()
+    ],
+    bump = creator_vault.bump,
+  )]
+  pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+  #[account(
+    mut,
+    seeds = [
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump = platform_fee_vault.bump
+  )]
+  pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+  // optional, takes referral_fee_bps of the platform fee
+  #[account(
+    mut,
+    seeds = [
+      REFERRER_SEED,
+      referrer.authority.as_ref()
+    ],
+    bump = referrer.bump,
+    constraint = referrer.authority != user.key() @PumpFunError::SelfReferral,
   )]
-  pub bonding_curve: UncheckedAccount<'info>,
+  pub referrer: Option<Box<Account<'info, Referrer>>>,
 
   #[account(
     mut,
@@ -37,6 +67,18 @@ pub struct BuyInSol<'info> {
   )]
   pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
 
+  /// CHECK: per-wallet launch window tally, created in apply while the window is open
+  #[account(
+    mut,
+    seeds = [
+      LAUNCH_ALLOCATION_SEED,
+      token_mint.key().as_ref(),
+      user.key().as_ref()
+    ],
+    bump,
+  )]
+  pub launch_allocation: UncheckedAccount<'info>,
+
   #[account(
     init_if_needed,
     associated_token::mint = token_mint,
@@ -49,45 +91,86 @@ pub struct BuyInSol<'info> {
   #[account(mut)]
     pub user: Signer<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 impl BuyInSol<'_> {
-  pub fn apply(ctx: &mut Context<BuyInSol>, amount_min: u64, sol: u64) -> Result<()> {
+  pub fn apply(ctx: &mut Context<BuyInSol>, amount_min: u64, sol: u64, allow_partial: bool) -> Result<()> {
     let decimals = ctx.accounts.token_mint.decimals;
-
-    // check to ensure funding goal is not met
+    ctx.accounts.bonding_curve.check_trading_open(&Clock::get()?)?;
+
+    let vault_amount = ctx.accounts.associted_bonding_curve.amount;
+
+    let available = available_supply(vault_amount)?;
+
+    // fees come out of the SOL sent, the rest goes into the curve
+    let mut fees = Self::fees(ctx, sol);
+    let mut sol_in = sol.checked_sub(fees.total()).ok_or(PumpFunError::MathOverflow)?;
+
+    // checked against the funding goal and the available supply
+    let mut token_amount_to_purchased = buy_amount(vault_amount, sol_in, decimals)?;
+    if allow_partial && token_amount_to_purchased > available {
+        // fill the remaining supply, the user is charged the smallest amount that covers
+        // its cost once the fees are taken out, like the full path fees are on the amount charged
+        token_amount_to_purchased = available;
+        let gross = gross_up(
+            buy_cost(vault_amount, available, decimals)?,
+            ctx.accounts.bonding_curve.creator_fee_bps,
+            ctx.accounts.global_config.fee_rate,
+        )
+        .ok_or(PumpFunError::MathOverflow)?;
+        fees = Self::fees(ctx, gross);
+        sol_in = gross - fees.total();
+    }
+    let charged = sol_in.checked_add(fees.total()).ok_or(PumpFunError::MathOverflow)?;
+    require!(charged <= sol, PumpFunError::InvalidSolAmount);
+
+    // amount_min is for the full sol, a partial fill must keep its price
     require!(
-        ctx.accounts.associted_bonding_curve.amount > INIT_SUPPLY,
-        PumpFunError::AlreadyRaised
+        price_at_least(token_amount_to_purchased, charged, amount_min, sol),
+        PumpFunError::SlippageExceed
     );
-    let current_supply =
-      MAX_SUPPLY - ctx.accounts.associted_bonding_curve.amount;
-
-    let token_amount_to_purchased = calculate_token_amount(current_supply, sol, decimals);
-    require!(token_amount_to_purchased >= amount_min, PumpFunError::SlippageExceed);
+    require!(token_amount_to_purchased <= available, PumpFunError::NotEnoughSuppply);
 
-    let available_qty =
-        ctx.accounts.associted_bonding_curve.amount - INIT_SUPPLY;
-
-    require!(token_amount_to_purchased <= available_qty, PumpFunError::NotEnoughSuppply);
+    ctx.accounts.bonding_curve.check_holding(
+        ctx.accounts.associted_user_token_account.amount
+            .checked_add(token_amount_to_purchased)
+            .ok_or(PumpFunError::MathOverflow)?,
+    )?;
 
+    // launch window caps, the creator's initial buy in create_token is exempt
+    LaunchAllocation::check_buy(
+        &ctx.accounts.bonding_curve,
+        &ctx.accounts.launch_allocation.to_account_info(),
+        ctx.bumps.launch_allocation,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        token_amount_to_purchased,
+    )?;
 
     //transfer sol to vault
     transfer_sol(
         ctx.accounts.user.to_account_info(),
         ctx.accounts.bonding_curve.to_account_info(),
-        sol,
+        sol_in,
     )?;
     //transfer fee
+    pay_trade_fees(
+        &ctx.accounts.user.to_account_info(),
+        &fees,
+        Some(&mut *ctx.accounts.creator_vault),
+        &ctx.accounts.platform_fee_vault.to_account_info(),
+        ctx.accounts.referrer.as_deref_mut(),
+        &mut ctx.accounts.global_config,
+    )?;
 
     //transfer token from vault to user
     let token_mint = ctx.accounts.token_mint.key();
     let vault_seeds = &[
         BONDING_CURVE_SEED,
         token_mint.as_ref(),
-        &[ctx.bumps.bonding_curve],
+        &[ctx.accounts.bonding_curve.bump],
     ];
     let vault_signer_seeds = &[&vault_seeds[..]];
 
@@ -101,12 +184,41 @@ impl BuyInSol<'_> {
         decimals,
         vault_signer_seeds,
     )?;
+    ctx.accounts.bonding_curve.record_buy(token_amount_to_purchased, sol_in)?;
     emit!(BuyEvent {
         mint: ctx.accounts.token_mint.key(),
         token_output: token_amount_to_purchased,
-        sol_input: sol,
-        buyer: ctx.accounts.user.key()
+        sol_input: sol_in,
+        buyer: ctx.accounts.user.key(),
+        creator_fee: fees.creator_fee,
+        platform_fee: fees.platform_fee,
+        referral_fee: fees.referral_fee,
+        referrer: ctx.accounts.referrer.as_ref().map(|referrer| referrer.authority),
     });
-    Ok(())
+
+    if BondingCurve::completes(vault_amount - token_amount_to_purchased) {
+        BondingCurve::complete(
+            &mut ctx.accounts.bonding_curve,
+            &mut ctx.accounts.global_config,
+            ctx.accounts.platform_fee_vault.to_account_info(),
+            Some(ctx.accounts.creator_vault.as_mut()),
+        )?;
+    }
+
+    ctx.accounts.associted_bonding_curve.reload()?;
+    ctx.accounts.bonding_curve.assert_invariants(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        ctx.accounts.associted_bonding_curve.amount,
+        decimals,
+    )
+  }
+
+  pub fn fees(ctx: &Context<BuyInSol>, sol_amount: u64) -> TradeFees {
+    TradeFees::new(
+      sol_amount,
+      ctx.accounts.bonding_curve.creator_fee_bps,
+      ctx.accounts.global_config.fee_rate,
+      ctx.accounts.referrer.as_ref().map(|_| ctx.accounts.global_config.referral_fee_bps),
+    )
   }
 }
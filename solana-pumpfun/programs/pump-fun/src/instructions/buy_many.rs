@@ -0,0 +1,227 @@
+use crate::*;
+
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken, Create},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+// remaining accounts per leg: token_mint, bonding_curve, associted_bonding_curve,
+// launch_allocation, associted_user_token_account
+pub const BUY_LEG_ACCOUNTS_LEN: usize = 5;
+
+/// Buys several mints with SOL in one instruction, each leg priced like `buy_in_sol`.
+#[derive(Accounts)]
+pub struct BuyMany<'info> {
+  #[account(
+    mut,
+    seeds = [
+      CONFIG_SEED,
+    ],
+    bump = global_config.bump
+  )]
+  pub global_config: Box<Account<'info, GlobalConfig>>,
+
+  #[account(
+    mut,
+    seeds = [
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump = platform_fee_vault.bump
+  )]
+  pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+  // optional, takes referral_fee_bps of the platform fee
+  #[account(
+    mut,
+    seeds = [
+      REFERRER_SEED,
+      referrer.authority.as_ref()
+    ],
+    bump = referrer.bump,
+    constraint = referrer.authority != user.key() @PumpFunError::SelfReferral,
+  )]
+  pub referrer: Option<Box<Account<'info, Referrer>>>,
+
+  #[account(mut)]
+    pub user: Signer<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BuyMany<'info> {
+  pub fn apply(ctx: &mut Context<'_, '_, 'info, 'info, BuyMany<'info>>, params: &BuyManyParams) -> Result<()> {
+    require!(
+        !params.legs.is_empty() && ctx.remaining_accounts.len() == params.legs.len() * BUY_LEG_ACCOUNTS_LEN,
+        PumpFunError::InvalidInput
+    );
+    let remaining_accounts = ctx.remaining_accounts;
+    for (leg, accounts) in params.legs.iter().zip(remaining_accounts.chunks(BUY_LEG_ACCOUNTS_LEN)) {
+        Self::buy_leg(ctx, leg, accounts)?;
+    }
+    Ok(())
+  }
+
+  fn buy_leg(
+    ctx: &mut Context<'_, '_, 'info, 'info, BuyMany<'info>>,
+    leg: &BuyLeg,
+    accounts: &'info [AccountInfo<'info>],
+  ) -> Result<()> {
+    let [mint_info, curve_info, vault_info, allocation_info, user_token_info] = accounts else {
+        return err!(PumpFunError::InvalidInput);
+    };
+    let user = ctx.accounts.user.key();
+    let token_program = ctx.accounts.token_program.key();
+
+    // every account of the leg is derived from its mint
+    require!(mint_info.key() == leg.mint, PumpFunError::InvalidBuyLeg);
+    let token_mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+    let mut bonding_curve = Account::<BondingCurve>::try_from(curve_info)?;
+    let (launch_allocation, allocation_bump) = Pubkey::find_program_address(
+        &[LAUNCH_ALLOCATION_SEED, leg.mint.as_ref(), user.as_ref()],
+        &crate::ID,
+    );
+    require!(
+        curve_info.key()
+            == Pubkey::create_program_address(
+                &[BONDING_CURVE_SEED, leg.mint.as_ref(), &[bonding_curve.bump]],
+                &crate::ID,
+            )
+            .map_err(|_| PumpFunError::InvalidBuyLeg)?
+            && bonding_curve.token_program == token_program
+            && vault_info.key() == get_associated_token_address_with_program_id(curve_info.key, &leg.mint, &token_program)
+            && allocation_info.key() == launch_allocation
+            && user_token_info.key() == get_associated_token_address_with_program_id(&user, &leg.mint, &token_program),
+        PumpFunError::InvalidBuyLeg
+    );
+    let decimals = token_mint.decimals;
+    bonding_curve.check_trading_open(&Clock::get()?)?;
+
+    if user_token_info.data_is_empty() {
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: ctx.accounts.user.to_account_info(),
+                associated_token: user_token_info.clone(),
+                authority: ctx.accounts.user.to_account_info(),
+                mint: mint_info.clone(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+    }
+    let vault_amount = InterfaceAccount::<TokenAccount>::try_from(vault_info)?.amount;
+    let balance = InterfaceAccount::<TokenAccount>::try_from(user_token_info)?.amount;
+
+    // fees come out of the SOL of the leg, the rest goes into the curve
+    let fees = TradeFees::new(
+        leg.sol_amount,
+        bonding_curve.creator_fee_bps,
+        ctx.accounts.global_config.fee_rate,
+        ctx.accounts.referrer.as_ref().map(|_| ctx.accounts.global_config.referral_fee_bps),
+    );
+    let sol_in = leg.sol_amount.checked_sub(fees.total()).ok_or(PumpFunError::MathOverflow)?;
+
+    // checked against the funding goal and the available supply
+    let token_amount_to_purchased = buy_amount(vault_amount, sol_in, decimals)?;
+    require!(token_amount_to_purchased >= leg.min_tokens, PumpFunError::SlippageExceed);
+    require!(
+        token_amount_to_purchased <= available_supply(vault_amount)?,
+        PumpFunError::NotEnoughSuppply
+    );
+
+    bonding_curve.check_holding(
+        balance
+            .checked_add(token_amount_to_purchased)
+            .ok_or(PumpFunError::MathOverflow)?,
+    )?;
+    LaunchAllocation::check_buy(
+        &bonding_curve,
+        allocation_info,
+        allocation_bump,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        token_amount_to_purchased,
+    )?;
+
+    // creator vaults are not passed per leg, the creator fee waits on the curve
+    transfer_sol(
+        ctx.accounts.user.to_account_info(),
+        curve_info.clone(),
+        sol_in.checked_add(fees.creator_fee).ok_or(PumpFunError::MathOverflow)?,
+    )?;
+    bonding_curve.creator_fees_unswept = bonding_curve
+        .creator_fees_unswept
+        .checked_add(fees.creator_fee)
+        .ok_or(PumpFunError::MathOverflow)?;
+    pay_trade_fees(
+        &ctx.accounts.user.to_account_info(),
+        &fees,
+        None,
+        &ctx.accounts.platform_fee_vault.to_account_info(),
+        ctx.accounts.referrer.as_deref_mut(),
+        &mut ctx.accounts.global_config,
+    )?;
+
+    //transfer token from vault to user
+    let vault_seeds = &[
+        BONDING_CURVE_SEED,
+        leg.mint.as_ref(),
+        &[bonding_curve.bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    transfer_token_from_vault_to_user(
+        curve_info.clone(),
+        vault_info.clone(),
+        user_token_info.clone(),
+        mint_info.clone(),
+        ctx.accounts.token_program.to_account_info(),
+        token_amount_to_purchased,
+        decimals,
+        vault_signer_seeds,
+    )?;
+    bonding_curve.record_buy(token_amount_to_purchased, sol_in)?;
+    emit!(BuyEvent {
+        mint: leg.mint,
+        token_output: token_amount_to_purchased,
+        sol_input: sol_in,
+        buyer: user,
+        creator_fee: fees.creator_fee,
+        platform_fee: fees.platform_fee,
+        referral_fee: fees.referral_fee,
+        referrer: ctx.accounts.referrer.as_ref().map(|referrer| referrer.authority),
+    });
+
+    if BondingCurve::completes(vault_amount - token_amount_to_purchased) {
+        BondingCurve::complete(
+            &mut bonding_curve,
+            &mut ctx.accounts.global_config,
+            ctx.accounts.platform_fee_vault.to_account_info(),
+            None,
+        )?;
+    }
+
+    bonding_curve.assert_invariants(
+        curve_info,
+        InterfaceAccount::<TokenAccount>::try_from(vault_info)?.amount,
+        decimals,
+    )?;
+    // not part of the accounts struct, written back here so a later leg on the same mint sees it
+    bonding_curve.exit(&crate::ID)
+  }
+
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct BuyLeg {
+  pub mint: Pubkey,
+  // fees included, like the sol of buy_in_sol
+  pub sol_amount: u64,
+  pub min_tokens: u64,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct BuyManyParams {
+  pub legs: Vec<BuyLeg>,
+}
@@ -0,0 +1,196 @@
+use crate::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Allowlisted buy at the curve price before trading opens, up to the wallet's allocation.
+#[derive(Accounts)]
+pub struct BuyPresale<'info> {
+  pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+  #[account(
+    mut,
+    seeds = [
+      CONFIG_SEED,
+    ],
+    bump = global_config.bump
+  )]
+  pub global_config: Box<Account<'info, GlobalConfig>>,
+
+  #[account(
+    mut,
+    seeds = [
+      BONDING_CURVE_SEED,
+      token_mint.key().as_ref()
+    ],
+    bump = bonding_curve.bump,
+  )]
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    mut,
+    seeds = [
+      CREATOR_VAULT_SEED,
+      bonding_curve.creator.as_ref()
+    ],
+    bump = creator_vault.bump,
+  )]
+  pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+  #[account(
+    mut,
+    seeds = [
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump = platform_fee_vault.bump
+  )]
+  pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+  #[account(
+    mut,
+    associated_token::mint = token_mint,
+    associated_token::authority = bonding_curve,
+    token::token_program = token_program,
+  )]
+  pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    init_if_needed,
+    associated_token::mint = token_mint,
+    associated_token::authority = user,
+    token::token_program = token_program,
+    payer = user,
+  )]
+  pub associted_user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    init_if_needed,
+    seeds = [
+      PRESALE_CLAIM_SEED,
+      token_mint.key().as_ref(),
+      user.key().as_ref()
+    ],
+    bump,
+    payer = user,
+    space = 8 + PresaleClaim::INIT_SPACE,
+  )]
+  pub presale_claim: Box<Account<'info, PresaleClaim>>,
+
+  #[account(mut)]
+    pub user: Signer<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl BuyPresale<'_> {
+  pub fn apply(ctx: &mut Context<BuyPresale>, params: &BuyPresaleParams) -> Result<()> {
+    let decimals = ctx.accounts.token_mint.decimals;
+    let root = ctx.accounts.bonding_curve.presale_merkle_root.ok_or(PumpFunError::PresaleNotEnabled)?;
+    require!(
+        Clock::get()?.unix_timestamp < ctx.accounts.bonding_curve.trading_start_ts,
+        PumpFunError::PresaleClosed
+    );
+    require!(
+        verify_proof(&params.proof, &root, presale_leaf(&ctx.accounts.user.key(), params.allocation)),
+        PumpFunError::InvalidPresaleProof
+    );
+
+    let claim = &mut ctx.accounts.presale_claim;
+    if claim.user == Pubkey::default() {
+        claim.token_mint = ctx.accounts.token_mint.key();
+        claim.user = ctx.accounts.user.key();
+        claim.bump = ctx.bumps.presale_claim;
+    }
+    claim.bought = claim.bought.checked_add(params.amount).ok_or(PumpFunError::MathOverflow)?;
+    require!(claim.bought <= params.allocation, PumpFunError::PresaleAllocationExceeded);
+
+    // checked against the funding goal and the available supply
+    let vault_amount = ctx.accounts.associted_bonding_curve.amount;
+    let required_lamports = buy_cost(vault_amount, params.amount, decimals)?;
+    let fees = TradeFees::new(
+        required_lamports,
+        ctx.accounts.bonding_curve.creator_fee_bps,
+        ctx.accounts.global_config.fee_rate,
+        None,
+    );
+    require!(
+        params.max_sol_cost >= required_lamports.checked_add(fees.total()).ok_or(PumpFunError::MathOverflow)?,
+        PumpFunError::InvalidSolAmount
+    );
+
+    //transfer sol to vault
+    transfer_sol(
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.bonding_curve.to_account_info(),
+        required_lamports,
+    )?;
+    //transfer fee
+    pay_trade_fees(
+        &ctx.accounts.user.to_account_info(),
+        &fees,
+        Some(&mut *ctx.accounts.creator_vault),
+        &ctx.accounts.platform_fee_vault.to_account_info(),
+        None,
+        &mut ctx.accounts.global_config,
+    )?;
+
+    //transfer token from vault to user
+    let token_mint = ctx.accounts.token_mint.key();
+    let vault_seeds = &[
+        BONDING_CURVE_SEED,
+        token_mint.as_ref(),
+        &[ctx.accounts.bonding_curve.bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    transfer_token_from_vault_to_user(
+        ctx.accounts.bonding_curve.to_account_info(),
+        ctx.accounts.associted_bonding_curve.to_account_info(),
+        ctx.accounts.associted_user_token_account.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        params.amount,
+        decimals,
+        vault_signer_seeds,
+    )?;
+    ctx.accounts.bonding_curve.record_buy(params.amount, required_lamports)?;
+    emit!(BuyEvent {
+        mint: ctx.accounts.token_mint.key(),
+        token_output: params.amount,
+        sol_input: required_lamports,
+        buyer: ctx.accounts.user.key(),
+        creator_fee: fees.creator_fee,
+        platform_fee: fees.platform_fee,
+        referral_fee: 0,
+        referrer: None,
+    });
+
+    if BondingCurve::completes(vault_amount - params.amount) {
+        BondingCurve::complete(
+            &mut ctx.accounts.bonding_curve,
+            &mut ctx.accounts.global_config,
+            ctx.accounts.platform_fee_vault.to_account_info(),
+            Some(ctx.accounts.creator_vault.as_mut()),
+        )?;
+    }
+
+    ctx.accounts.associted_bonding_curve.reload()?;
+    ctx.accounts.bonding_curve.assert_invariants(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        ctx.accounts.associted_bonding_curve.amount,
+        decimals,
+    )
+  }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct BuyPresaleParams {
+  pub amount: u64,
+  pub max_sol_cost: u64,
+  // allowlisted token allocation of the wallet, part of the leaf
+  pub allocation: u64,
+  pub proof: Vec<[u8; 32]>,
+}
@@ -0,0 +1,37 @@
+use crate::*;
+
+/// Closes the DCA vault, the unspent SOL and the rent go back to the owner.
+#[derive(Accounts)]
+pub struct CancelDca<'info> {
+  #[account(
+    mut,
+    seeds = [
+      DCA_VAULT_SEED,
+      dca_vault.token_mint.as_ref(),
+      owner.key().as_ref()
+    ],
+    bump = dca_vault.bump,
+    has_one = owner,
+    close = owner,
+  )]
+  pub dca_vault: Box<Account<'info, DcaVault>>,
+
+  #[account(mut)]
+  pub owner: Signer<'info>,
+}
+
+impl CancelDca<'_> {
+  pub fn apply(ctx: &mut Context<CancelDca>) -> Result<()> {
+    let info = ctx.accounts.dca_vault.to_account_info();
+    let refunded = info.lamports().saturating_sub(Rent::get()?.minimum_balance(info.data_len()));
+    emit!(CancelDcaEvent {
+        dca_vault: ctx.accounts.dca_vault.key(),
+        mint: ctx.accounts.dca_vault.token_mint,
+        owner: ctx.accounts.owner.key(),
+        refunded,
+        total_spent: ctx.accounts.dca_vault.total_spent,
+        total_tokens: ctx.accounts.dca_vault.total_tokens,
+    });
+    Ok(())
+  }
+}
@@ -0,0 +1,89 @@
+use crate::*;
+
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Returns the deposit and tip of an unfilled order to its owner and closes it.
+#[derive(Accounts)]
+pub struct CancelLimitOrder<'info> {
+  pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+  #[account(
+    mut,
+    seeds = [
+      LIMIT_ORDER_SEED,
+      token_mint.key().as_ref(),
+      owner.key().as_ref(),
+      &limit_order.order_id.to_le_bytes()
+    ],
+    bump = limit_order.bump,
+    has_one = owner,
+    close = owner,
+  )]
+  pub limit_order: Box<Account<'info, LimitOrder>>,
+
+  #[account(
+    mut,
+    associated_token::mint = token_mint,
+    associated_token::authority = limit_order,
+    token::token_program = token_program,
+  )]
+  pub order_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    associated_token::mint = token_mint,
+    associated_token::authority = owner,
+    token::token_program = token_program,
+  )]
+  pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl CancelLimitOrder<'_> {
+  pub fn apply(ctx: &mut Context<CancelLimitOrder>) -> Result<()> {
+    let token_mint = ctx.accounts.token_mint.key();
+    let owner = ctx.accounts.owner.key();
+    let order_id = ctx.accounts.limit_order.order_id.to_le_bytes();
+    let order_seeds = &[
+        LIMIT_ORDER_SEED,
+        token_mint.as_ref(),
+        owner.as_ref(),
+        &order_id,
+        &[ctx.accounts.limit_order.bump],
+    ];
+    let order_signer_seeds = &[&order_seeds[..]];
+
+    let tokens = ctx.accounts.order_token_account.amount;
+    if tokens > 0 {
+      transfer_token_from_vault_to_user(
+          ctx.accounts.limit_order.to_account_info(),
+          ctx.accounts.order_token_account.to_account_info(),
+          ctx.accounts.owner_token_account.to_account_info(),
+          ctx.accounts.token_mint.to_account_info(),
+          ctx.accounts.token_program.to_account_info(),
+          tokens,
+          ctx.accounts.token_mint.decimals,
+          order_signer_seeds,
+      )?;
+    }
+    close_token_account_from_vault(
+        ctx.accounts.limit_order.to_account_info(),
+        ctx.accounts.order_token_account.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        order_signer_seeds,
+    )?;
+
+    // SOL deposit, tip and rent go back with `close = owner`
+    emit!(LimitOrderCancelledEvent {
+        order: ctx.accounts.limit_order.key(),
+        mint: token_mint,
+        owner,
+        order_id: ctx.accounts.limit_order.order_id,
+    });
+    Ok(())
+  }
+}
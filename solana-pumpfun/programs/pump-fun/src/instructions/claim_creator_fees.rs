@@ -0,0 +1,62 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+  #[account(mut)]
+  pub creator: Signer<'info>,
+
+  #[account(
+    mut,
+    seeds = [
+      CREATOR_VAULT_SEED,
+      creator.key().as_ref()
+    ],
+    bump = creator_vault.bump,
+    has_one = creator,
+  )]
+  pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+  // optional, sweeps the fees of inbound cross-chain buys held on this curve first
+  #[account(
+    mut,
+    seeds = [
+      BONDING_CURVE_SEED,
+      bonding_curve.token_mint.as_ref()
+    ],
+    bump = bonding_curve.bump,
+    has_one = creator,
+  )]
+  pub bonding_curve: Option<Box<Account<'info, BondingCurve>>>,
+}
+
+impl ClaimCreatorFees<'_> {
+  pub fn apply(ctx: &mut Context<ClaimCreatorFees>) -> Result<()> {
+    let accounts = &mut ctx.accounts;
+    let swept = match accounts.bonding_curve.as_mut() {
+      Some(curve) => CreatorVault::sweep(&mut accounts.creator_vault, curve)?,
+      None => 0,
+    };
+
+    let amount = accounts.creator_vault.claimable();
+    require!(amount > 0, PumpFunError::NothingToClaim);
+
+    transfer_sol_from_vault_to_user(
+      accounts.creator_vault.to_account_info(),
+      accounts.creator.to_account_info(),
+      amount,
+    )?;
+    accounts.creator_vault.total_claimed = accounts.creator_vault
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(PumpFunError::MathOverflow)?;
+
+    emit!(ClaimCreatorFeesEvent {
+      creator: accounts.creator.key(),
+      amount,
+      swept,
+      total_accrued: accounts.creator_vault.total_accrued,
+      total_claimed: accounts.creator_vault.total_claimed,
+    });
+    Ok(())
+  }
+}
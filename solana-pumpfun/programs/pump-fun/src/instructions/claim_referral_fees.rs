@@ -0,0 +1,44 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+  #[account(mut)]
+  pub authority: Signer<'info>,
+
+  #[account(
+    mut,
+    seeds = [
+      REFERRER_SEED,
+      authority.key().as_ref()
+    ],
+    bump = referrer.bump,
+    has_one = authority,
+  )]
+  pub referrer: Box<Account<'info, Referrer>>,
+}
+
+impl ClaimReferralFees<'_> {
+  pub fn apply(ctx: &mut Context<ClaimReferralFees>) -> Result<()> {
+    let amount = ctx.accounts.referrer.claimable();
+    require!(amount > 0, PumpFunError::NothingToClaim);
+
+    transfer_sol_from_vault_to_user(
+      ctx.accounts.referrer.to_account_info(),
+      ctx.accounts.authority.to_account_info(),
+      amount,
+    )?;
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.total_claimed = referrer
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(PumpFunError::MathOverflow)?;
+
+    emit!(ClaimReferralFeesEvent {
+      authority: ctx.accounts.authority.key(),
+      amount,
+      total_accrued: referrer.total_accrued,
+      total_claimed: referrer.total_claimed,
+    });
+    Ok(())
+  }
+}
@@ -0,0 +1,36 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: ClosePeerParams)]
+pub struct ClosePeer<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PEER_SEED, &oapp_config.key().to_bytes(), &params.dst_eid.to_be_bytes()],
+        bump = peer.bump,
+        close = admin
+    )]
+    pub peer: Box<Account<'info, Peer>>,
+    #[account(
+        seeds = [OAPP_SEED],
+        bump = oapp_config.bump,
+        has_one = admin @OftError::Unauthorized
+    )]
+    pub oapp_config: Box<Account<'info, OAppConfig>>,
+}
+
+impl ClosePeer<'_> {
+    pub fn apply(ctx: &mut Context<ClosePeer>, params: &ClosePeerParams) -> Result<()> {
+        emit!(PeerRemoved {
+            eid: params.dst_eid,
+            peer: ctx.accounts.peer.address,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ClosePeerParams {
+    pub dst_eid: u32,
+}
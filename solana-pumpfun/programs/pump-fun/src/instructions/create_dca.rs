@@ -0,0 +1,104 @@
+use crate::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Opens the DCA vault of the owner for a mint and deposits the SOL to spend.
+#[derive(Accounts)]
+pub struct CreateDca<'info> {
+  pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+  #[account(
+    seeds = [
+      CONFIG_SEED,
+    ],
+    bump = global_config.bump
+  )]
+  pub global_config: Box<Account<'info, GlobalConfig>>,
+
+  #[account(
+    seeds = [
+      BONDING_CURVE_SEED,
+      token_mint.key().as_ref()
+    ],
+    bump = bonding_curve.bump,
+  )]
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    init,
+    payer = owner,
+    space = 8 + DcaVault::INIT_SPACE,
+    seeds = [
+      DCA_VAULT_SEED,
+      token_mint.key().as_ref(),
+      owner.key().as_ref()
+    ],
+    bump,
+  )]
+  pub dca_vault: Box<Account<'info, DcaVault>>,
+
+  // created here so keepers only need to credit it
+  #[account(
+    init_if_needed,
+    associated_token::mint = token_mint,
+    associated_token::authority = owner,
+    token::token_program = token_program,
+    payer = owner,
+  )]
+  pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(mut)]
+    pub owner: Signer<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateDca<'_> {
+  pub fn apply(ctx: &mut Context<CreateDca>, params: &CreateDcaParams) -> Result<()> {
+    require!(!ctx.accounts.bonding_curve.complete, PumpFunError::AlreadyRaised);
+    require!(params.deposit > 0, PumpFunError::InvalidDcaParams);
+
+    **ctx.accounts.dca_vault = DcaVault::new(
+        ctx.accounts.owner.key(),
+        ctx.accounts.token_mint.key(),
+        ctx.bumps.dca_vault,
+        params,
+    );
+    // every slice must cover its fees and still buy
+    ctx.accounts.dca_vault.validate(DcaVault::min_slice(
+        ctx.accounts.bonding_curve.creator_fee_bps,
+        ctx.accounts.global_config.fee_rate,
+    )?)?;
+
+    transfer_sol(
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.dca_vault.to_account_info(),
+        params.deposit,
+    )?;
+
+    emit!(CreateDcaEvent {
+        dca_vault: ctx.accounts.dca_vault.key(),
+        mint: ctx.accounts.token_mint.key(),
+        owner: ctx.accounts.owner.key(),
+        deposit: params.deposit,
+        amount_per_interval: params.amount_per_interval,
+        interval_seconds: params.interval_seconds,
+        max_price: params.max_price,
+    });
+    Ok(())
+  }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CreateDcaParams {
+  pub deposit: u64,
+  // lamports per buy, fees included, at least DcaVault::min_slice
+  pub amount_per_interval: u64,
+  pub interval_seconds: i64,
+  // lamports per whole token, fees included, slices above it wait
+  pub max_price: u64,
+}
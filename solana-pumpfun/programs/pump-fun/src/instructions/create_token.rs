@@ -1,30 +1,57 @@
 use crate::*;
+use anchor_lang::{
+  solana_program::{program::invoke_signed, program_pack::Pack},
+  system_program::{create_account, CreateAccount},
+};
 use anchor_spl::{
-  associated_token::AssociatedToken,
+  associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken, Create},
   metadata::{
       create_metadata_accounts_v3,
-      mpl_token_metadata::{accounts::Metadata as MetadataAccount, types::DataV2},
-      CreateMetadataAccountsV3, Metadata,
+      mpl_token_metadata::{
+          accounts::Metadata as MetadataAccount, types::DataV2, MAX_NAME_LENGTH,
+          MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+      },
+      update_metadata_accounts_v2, CreateMetadataAccountsV3, Metadata,
+      UpdateMetadataAccountsV2,
+  },
+  token_2022::{
+    self,
+    spl_token_2022::{
+      extension::{metadata_pointer, ExtensionType},
+      instruction::AuthorityType,
+      state::Mint as MintState,
+    },
+  },
+  token_interface::{
+    initialize_mint2, mint_to, set_authority, InitializeMint2, MintTo, SetAuthority,
+    TokenAccount, TokenInterface,
   },
-  token::Token,
-  token_interface::{Mint, MintTo, TokenAccount, mint_to},
 };
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_metadata_interface::state::TokenMetadata;
+
+pub const TOKEN_DECIMALS: u8 = 9;
 
+/// The creator picks the token program: legacy SPL mints get Metaplex metadata,
+/// Token-2022 mints keep their metadata on the mint (metadata-pointer + token-metadata).
 #[derive(Accounts)]
 pub struct CreateToken<'info> {
   #[account(mut)]
   pub payer: Signer<'info>,
 
+  /// new mint keypair, created and initialized in apply for the chosen token program
+  #[account(mut)]
+  pub token_mint: Signer<'info>,
+
   #[account(
-    init,
-    payer = payer,
-    mint::decimals = 9,
-    mint::authority = bonding_curve,
-    mint::token_program = token_program,
+    mut,
+    seeds = [
+      CONFIG_SEED,
+    ],
+    bump = global_config.bump
   )]
-  pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+  pub global_config: Box<Account<'info, GlobalConfig>>,
 
-  /// CHECK
   #[account(
     init,
     seeds = [
@@ -32,31 +59,56 @@ pub struct CreateToken<'info> {
       token_mint.key().as_ref()
     ],
     payer = payer,
-    space = 0,
+    space = 8 + BondingCurve::INIT_SPACE,
     bump,
   )]
-  pub bonding_curve: UncheckedAccount<'info>,
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
 
+  // shared by every token of this creator
   #[account(
-    init,
-    // mut,
-    associated_token::mint = token_mint,
-    associated_token::authority = bonding_curve,
+    init_if_needed,
+    seeds = [
+      CREATOR_VAULT_SEED,
+      payer.key().as_ref()
+    ],
     payer = payer,
-    token::token_program = token_program,
+    space = 8 + CreatorVault::INIT_SPACE,
+    bump,
   )]
-  pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
-
-  // #[account(
-  //   // init,
-  //   associated_token::mint = token_mint,
-  //   associated_token::authority = payer,
-  //   // payer = payer,
-  //   token::token_program = token_program,
-  // )]
-  // pub associted_user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
-
-  /// CHECK
+  pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+  #[account(
+    mut,
+    seeds = [
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump = platform_fee_vault.bump
+  )]
+  pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+  /// CHECK: created in apply once the mint exists
+  #[account(
+    mut,
+    address = get_associated_token_address_with_program_id(
+      &bonding_curve.key(),
+      &token_mint.key(),
+      &token_program.key()
+    )
+  )]
+  pub associted_bonding_curve: UncheckedAccount<'info>,
+
+  /// CHECK: creator's token account, created in apply for the initial buy
+  #[account(
+    mut,
+    address = get_associated_token_address_with_program_id(
+      &payer.key(),
+      &token_mint.key(),
+      &token_program.key()
+    )
+  )]
+  pub associted_user_token_account: UncheckedAccount<'info>,
+
+  /// CHECK: metaplex metadata, only written for legacy SPL mints
   #[account(
     mut,
     address = MetadataAccount::find_pda(&token_mint.key()).0
@@ -64,7 +116,7 @@ pub struct CreateToken<'info> {
   pub metadata: UncheckedAccount<'info>,
 
   pub associated_token_program: Program<'info, AssociatedToken>,
-  pub token_program: Program<'info, Token>,
+  pub token_program: Interface<'info, TokenInterface>,
   pub token_metadata_program: Program<'info, Metadata>,
   pub rent: Sysvar<'info, Rent>,
   pub system_program: Program<'info, System>,
@@ -75,36 +127,128 @@ impl CreateToken<'_> {
     ctx: &mut Context<CreateToken>,
     params: &CreateTokenParams,
   ) -> Result<()> {
+    params.validate()?;
+    let creator_fee_bps = params.creator_fee_bps.unwrap_or(ctx.accounts.global_config.creator_fee_bps);
+    require!(
+      creator_fee_bps <= ctx.accounts.global_config.max_creator_fee_bps,
+      PumpFunError::InvalidFeeBps
+    );
 
     let seeds = &[BONDING_CURVE_SEED, &ctx.accounts.token_mint.key().to_bytes(), &[ctx.bumps.bonding_curve]];
     let signer_seeds = [&seeds[..]];
+    let is_token_2022 = ctx.accounts.token_program.key() == token_2022::ID;
+    let token_mint = ctx.accounts.token_mint.key();
+    let bonding_curve = ctx.accounts.bonding_curve.key();
 
-    // create metadata account
-    let cpi_context = CpiContext::new_with_signer(
-      ctx.accounts.token_metadata_program.to_account_info(),
-      CreateMetadataAccountsV3 {
-          metadata: ctx.accounts.metadata.to_account_info(),
-          mint: ctx.accounts.token_mint.to_account_info(),
-          mint_authority: ctx.accounts.bonding_curve.to_account_info(),
-          update_authority: ctx.accounts.bonding_curve.to_account_info(),
-          payer: ctx.accounts.payer.to_account_info(),
-          system_program: ctx.accounts.system_program.to_account_info(),
-          rent: ctx.accounts.rent.to_account_info(),
-      },
-      &signer_seeds,
-    );
-
-    let data_v2 = DataV2 {
-      name: String::from_utf8(params.name.clone()).unwrap(),
-      symbol: String::from_utf8(params.symbol.clone()).unwrap(),
-      uri: String::from_utf8(params.uri.clone()).unwrap(),
-      seller_fee_basis_points: 0,
-      creators: None,
-      collection: None,
-      uses: None,
+    // create the mint account, token-2022 mints are funded for the metadata written after init
+    let (space, lamports) = if is_token_2022 {
+      let space = ExtensionType::try_calculate_account_len::<MintState>(&[ExtensionType::MetadataPointer])?;
+      let metadata_space = TokenMetadata {
+        name: params.name.clone(),
+        symbol: params.symbol.clone(),
+        uri: params.uri.clone(),
+        ..Default::default()
+      }
+      .tlv_size_of()?;
+      (space, ctx.accounts.rent.minimum_balance(space + metadata_space))
+    } else {
+      (MintState::LEN, ctx.accounts.rent.minimum_balance(MintState::LEN))
     };
+    create_account(
+      CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        CreateAccount {
+          from: ctx.accounts.payer.to_account_info(),
+          to: ctx.accounts.token_mint.to_account_info(),
+        },
+      ),
+      lamports,
+      space as u64,
+      &ctx.accounts.token_program.key(),
+    )?;
+
+    if is_token_2022 {
+      // metadata lives on the mint itself
+      let ix = metadata_pointer::instruction::initialize(
+        &token_2022::ID,
+        &token_mint,
+        Some(bonding_curve),
+        Some(token_mint),
+      )?;
+      invoke_signed(&ix, &[ctx.accounts.token_mint.to_account_info()], &[])?;
+    }
 
-    create_metadata_accounts_v3(cpi_context, data_v2, false, true, None)?;
+    initialize_mint2(
+      CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        InitializeMint2 { mint: ctx.accounts.token_mint.to_account_info() },
+      ),
+      TOKEN_DECIMALS,
+      &bonding_curve,
+      None,
+    )?;
+
+    if is_token_2022 {
+      let ix = spl_token_metadata_interface::instruction::initialize(
+        &token_2022::ID,
+        &token_mint,
+        &bonding_curve,
+        &token_mint,
+        &bonding_curve,
+        params.name.clone(),
+        params.symbol.clone(),
+        params.uri.clone(),
+      );
+      invoke_signed(
+        &ix,
+        &[
+          ctx.accounts.token_mint.to_account_info(),
+          ctx.accounts.bonding_curve.to_account_info(),
+        ],
+        &signer_seeds,
+      )?;
+    } else {
+      // create metadata account
+      let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_metadata_program.to_account_info(),
+        CreateMetadataAccountsV3 {
+            metadata: ctx.accounts.metadata.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            mint_authority: ctx.accounts.bonding_curve.to_account_info(),
+            update_authority: ctx.accounts.bonding_curve.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        &signer_seeds,
+      );
+
+      let data_v2 = DataV2 {
+        name: params.name.clone(),
+        symbol: params.symbol.clone(),
+        uri: params.uri.clone(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+      };
+
+      let is_mutable = params.metadata_authority != MetadataAuthority::Immutable;
+      create_metadata_accounts_v3(cpi_context, data_v2, false, is_mutable, None)?;
+    }
+
+    // bonding curve token vault
+    associated_token::create(CpiContext::new(
+      ctx.accounts.associated_token_program.to_account_info(),
+      Create {
+        payer: ctx.accounts.payer.to_account_info(),
+        associated_token: ctx.accounts.associted_bonding_curve.to_account_info(),
+        authority: ctx.accounts.bonding_curve.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+      },
+    ))?;
 
     // mint_to  MAX_SUPPLY to bonding curve
     let cpi_accounts = MintTo {
@@ -116,25 +260,356 @@ impl CreateToken<'_> {
     let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
     mint_to(cpi_context.with_signer(&signer_seeds), MAX_SUPPLY)?;
 
+    // supply is fixed from here on
+    Self::revoke_authorities(ctx, params, is_token_2022, &signer_seeds)?;
+
+    let curve = &mut ctx.accounts.bonding_curve;
+    curve.token_mint = token_mint;
+    curve.creator = ctx.accounts.payer.key();
+    curve.token_program = ctx.accounts.token_program.key();
+    curve.bump = ctx.bumps.bonding_curve;
+    curve.mint_authority_revoked = true;
+    curve.freeze_authority_revoked = true; // mint is created without freeze authority
+    curve.metadata_authority = params.metadata_authority;
+    curve.creator_fee_bps = creator_fee_bps;
+    curve.creator_fees_unswept = 0;
+    curve.complete = false;
+    curve.tokens_sold = 0;
+    curve.tokens_withdrawn = 0;
+    curve.sol_reserve = 0;
+    // a start in the past opens trading right away
+    let clock = Clock::get()?;
+    curve.trading_start_ts = params.trading_start_ts.unwrap_or(0).max(clock.unix_timestamp);
+    // a scheduled launch opens the launch window with its first trade
+    curve.launch_slot = if curve.trading_start_ts == clock.unix_timestamp { clock.slot } else { 0 };
+    curve.launch_protection = params.launch_protection;
+    require!(
+      params.presale_merkle_root.is_none() || curve.trading_start_ts > clock.unix_timestamp,
+      PumpFunError::InvalidPresale
+    );
+    curve.presale_merkle_root = params.presale_merkle_root;
+    curve.max_holding_bps = params.max_holding_bps;
+
+    let creator_vault = &mut ctx.accounts.creator_vault;
+    if creator_vault.creator == Pubkey::default() {
+      creator_vault.creator = ctx.accounts.payer.key();
+      creator_vault.bump = ctx.bumps.creator_vault;
+    }
+
+    // creator buys first, in the same instruction so nobody can snipe in between
+    let (initial_buy_sol, initial_buy_tokens) = Self::initial_buy(ctx, params, &signer_seeds)?;
+
     emit!(CreateTokenEvent {
       creator: ctx.accounts.payer.key(),
-      token_name: String::from_utf8(params.name.to_vec()).unwrap(),
-      token_symbol: String::from_utf8(params.symbol.to_vec()).unwrap(),
-      token_uri: String::from_utf8(params.uri.to_vec()).unwrap(),
-      mint: ctx.accounts.token_mint.key()
+      token_name: params.name.clone(),
+      token_symbol: params.symbol.clone(),
+      token_uri: params.uri.clone(),
+      mint: ctx.accounts.token_mint.key(),
+      initial_buy_sol,
+      initial_buy_tokens,
     });
 
     Ok(())
   }
+
+  // drops the mint authority and hands over, keeps or drops the metadata update authority
+  fn revoke_authorities(
+    ctx: &mut Context<CreateToken>,
+    params: &CreateTokenParams,
+    is_token_2022: bool,
+    signer_seeds: &[&[&[u8]]],
+  ) -> Result<()> {
+    set_authority(
+      CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SetAuthority {
+          current_authority: ctx.accounts.bonding_curve.to_account_info(),
+          account_or_mint: ctx.accounts.token_mint.to_account_info(),
+        },
+      )
+      .with_signer(signer_seeds),
+      AuthorityType::MintTokens,
+      None,
+    )?;
+
+    if is_token_2022 {
+      // metadata can not be redirected to another account
+      set_authority(
+        CpiContext::new(
+          ctx.accounts.token_program.to_account_info(),
+          SetAuthority {
+            current_authority: ctx.accounts.bonding_curve.to_account_info(),
+            account_or_mint: ctx.accounts.token_mint.to_account_info(),
+          },
+        )
+        .with_signer(signer_seeds),
+        AuthorityType::MetadataPointer,
+        None,
+      )?;
+
+      let new_authority = match params.metadata_authority {
+        MetadataAuthority::Creator => return Ok(()),
+        MetadataAuthority::Immutable => None,
+        MetadataAuthority::Address(address) => Some(address),
+      };
+      let ix = spl_token_metadata_interface::instruction::update_authority(
+        &token_2022::ID,
+        &ctx.accounts.token_mint.key(),
+        &ctx.accounts.bonding_curve.key(),
+        OptionalNonZeroPubkey::try_from(new_authority)?,
+      );
+      invoke_signed(
+        &ix,
+        &[
+          ctx.accounts.token_mint.to_account_info(),
+          ctx.accounts.bonding_curve.to_account_info(),
+        ],
+        signer_seeds,
+      )?;
+    } else if let MetadataAuthority::Address(update_authority) = params.metadata_authority {
+      update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+          ctx.accounts.token_metadata_program.to_account_info(),
+          UpdateMetadataAccountsV2 {
+            metadata: ctx.accounts.metadata.to_account_info(),
+            update_authority: ctx.accounts.bonding_curve.to_account_info(),
+          },
+          signer_seeds,
+        ),
+        Some(update_authority),
+        None,
+        None,
+        None,
+      )?;
+    }
+    Ok(())
+  }
+
+  // returns (sol paid, tokens bought), zero when no initial buy was requested
+  fn initial_buy(
+    ctx: &mut Context<CreateToken>,
+    params: &CreateTokenParams,
+    signer_seeds: &[&[&[u8]]],
+  ) -> Result<(u64, u64)> {
+    // nothing is sold yet
+    let vault_amount = MAX_SUPPLY;
+    let fee_rate = ctx.accounts.global_config.fee_rate;
+    // platform fee like any buy, no creator fee since the creator would pay it to themselves
+    let (sol, amount, platform_fee) = match (params.initial_buy_sol, params.initial_buy_tokens) {
+      (None, None) => return Ok((0, 0)),
+      (Some(sol_total), None) => {
+        let platform_fee = calculate_platform_fee(sol_total, fee_rate);
+        let sol = sol_total - platform_fee;
+        let amount = buy_amount(vault_amount, sol, TOKEN_DECIMALS)?;
+        require!(amount >= params.initial_buy_limit, PumpFunError::SlippageExceed);
+        (sol, amount, platform_fee)
+      }
+      (None, Some(amount)) => {
+        let sol = buy_cost(vault_amount, amount, TOKEN_DECIMALS)?;
+        let platform_fee = calculate_platform_fee(sol, fee_rate);
+        require!(
+          params.initial_buy_limit >= sol.checked_add(platform_fee).ok_or(PumpFunError::MathOverflow)?,
+          PumpFunError::InvalidSolAmount
+        );
+        (sol, amount, platform_fee)
+      }
+      (Some(_), Some(_)) => return err!(PumpFunError::InvalidInput),
+    };
+    require!(sol > 0 && amount > 0, PumpFunError::InvalidInput);
+    require!(amount <= available_supply(vault_amount)?, PumpFunError::NotEnoughSuppply);
+    // exempt from the launch caps, not from the holding cap; the token account is new
+    ctx.accounts.bonding_curve.check_holding(amount)?;
+
+    associated_token::create(CpiContext::new(
+      ctx.accounts.associated_token_program.to_account_info(),
+      Create {
+        payer: ctx.accounts.payer.to_account_info(),
+        associated_token: ctx.accounts.associted_user_token_account.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+      },
+    ))?;
+
+    //transfer sol to vault
+    transfer_sol(
+      ctx.accounts.payer.to_account_info(),
+      ctx.accounts.bonding_curve.to_account_info(),
+      sol,
+    )?;
+    //transfer fee
+    if platform_fee > 0 {
+      transfer_sol(
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.platform_fee_vault.to_account_info(),
+        platform_fee,
+      )?;
+      ctx.accounts.global_config.total_platform_fees = ctx.accounts.global_config
+          .total_platform_fees
+          .checked_add(platform_fee)
+          .ok_or(PumpFunError::MathOverflow)?;
+    }
+
+    //transfer token from vault to creator
+    transfer_token_from_vault_to_user(
+      ctx.accounts.bonding_curve.to_account_info(),
+      ctx.accounts.associted_bonding_curve.to_account_info(),
+      ctx.accounts.associted_user_token_account.to_account_info(),
+      ctx.accounts.token_mint.to_account_info(),
+      ctx.accounts.token_program.to_account_info(),
+      amount,
+      TOKEN_DECIMALS,
+      signer_seeds,
+    )?;
+    ctx.accounts.bonding_curve.record_buy(amount, sol)?;
+    emit!(BuyEvent {
+      mint: ctx.accounts.token_mint.key(),
+      token_output: amount,
+      sol_input: sol,
+      buyer: ctx.accounts.payer.key(),
+      creator_fee: 0,
+      platform_fee,
+      referral_fee: 0,
+      referrer: None,
+    });
+
+    if BondingCurve::completes(vault_amount - amount) {
+      BondingCurve::complete(
+        &mut ctx.accounts.bonding_curve,
+        &mut ctx.accounts.global_config,
+        ctx.accounts.platform_fee_vault.to_account_info(),
+        Some(ctx.accounts.creator_vault.as_mut()),
+      )?;
+    }
+
+    let vault_amount = TokenAccount::try_deserialize(
+      &mut &ctx.accounts.associted_bonding_curve.try_borrow_data()?[..],
+    )?
+    .amount;
+    ctx.accounts.bonding_curve.assert_invariants(
+      &ctx.accounts.bonding_curve.to_account_info(),
+      vault_amount,
+      TOKEN_DECIMALS,
+    )?;
+    Ok((sol, amount))
+  }
 }
 
+pub const ALLOWED_URI_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
+
+// borsh already rejects strings that are not valid UTF-8
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct CreateTokenParams {
-  pub name: Vec<u8>,
-  pub symbol: Vec<u8>,
-  pub uri: Vec<u8>,
+  pub name: String,
+  pub symbol: String,
+  pub uri: String,
   // pub endpoint_program: Option<Pubkey>,
+  // optional creator buy, either with an amount of SOL or for an amount of tokens
+  pub initial_buy_sol: Option<u64>,
+  pub initial_buy_tokens: Option<u64>,
+  // min tokens out for initial_buy_sol, max SOL cost for initial_buy_tokens
+  pub initial_buy_limit: u64,
+  pub metadata_authority: MetadataAuthority,
+  // creator fee for this token, defaults to the config rate, capped by max_creator_fee_bps
+  pub creator_fee_bps: Option<u16>,
+  // caps buys right after creation, the initial buy is exempt
+  pub launch_protection: Option<LaunchProtection>,
+  // announced launch time, trading opens at creation when None
+  pub trading_start_ts: Option<i64>,
+  // allowlisted presale before trading_start_ts
+  pub presale_merkle_root: Option<[u8; 32]>,
+  // per-wallet cap in basis points of the total supply while the curve is active
+  pub max_holding_bps: Option<u16>,
+}
+
+impl CreateTokenParams {
+  pub fn validate(&self) -> Result<()> {
+    validate_token_name(&self.name)?;
+    validate_token_symbol(&self.symbol)?;
+    validate_token_uri(&self.uri)?;
+    if let Some(protection) = &self.launch_protection {
+      protection.validate()?;
+    }
+    if let Some(bps) = self.max_holding_bps {
+      require!(bps > 0 && bps as u64 <= BPS_DENOMINATOR, PumpFunError::InvalidMaxHolding);
+    }
+    Ok(())
+  }
+}
+
+// name: printable, no surrounding whitespace
+pub fn validate_token_name(name: &str) -> Result<()> {
+  require!(!name.is_empty(), PumpFunError::EmptyTokenName);
+  require!(name.len() <= MAX_NAME_LENGTH, PumpFunError::TokenNameTooLong);
+  require!(
+    name.trim() == name && !name.chars().any(char::is_control),
+    PumpFunError::InvalidTokenName
+  );
+  Ok(())
+}
+
+// symbol: ascii letters and digits only
+pub fn validate_token_symbol(symbol: &str) -> Result<()> {
+  require!(!symbol.is_empty(), PumpFunError::EmptyTokenSymbol);
+  require!(symbol.len() <= MAX_SYMBOL_LENGTH, PumpFunError::TokenSymbolTooLong);
+  require!(
+    symbol.chars().all(|c| c.is_ascii_alphanumeric()),
+    PumpFunError::InvalidTokenSymbol
+  );
+  Ok(())
+}
+
+// uri: known scheme followed by visible ascii
+pub fn validate_token_uri(uri: &str) -> Result<()> {
+  require!(!uri.is_empty(), PumpFunError::EmptyTokenUri);
+  require!(uri.len() <= MAX_URI_LENGTH, PumpFunError::TokenUriTooLong);
+  let scheme = ALLOWED_URI_SCHEMES
+    .iter()
+    .find(|scheme| uri.starts_with(*scheme))
+    .ok_or(PumpFunError::InvalidTokenUri)?;
+  require!(
+    uri.len() > scheme.len() && uri.chars().all(|c| c.is_ascii_graphic()),
+    PumpFunError::InvalidTokenUri
+  );
+  Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+  use super::*;
 
+  #[test]
+  fn test_validate_token_name() {
+    assert!(validate_token_name("Pump Token").is_ok());
+    assert!(validate_token_name(&"a".repeat(MAX_NAME_LENGTH)).is_ok());
+    assert!(validate_token_name("").is_err());
+    assert!(validate_token_name(&"a".repeat(MAX_NAME_LENGTH + 1)).is_err());
+    assert!(validate_token_name(" Pump").is_err());
+    assert!(validate_token_name("Pump\n").is_err());
+    assert!(validate_token_name("Pu\0mp").is_err());
+  }
+
+  #[test]
+  fn test_validate_token_symbol() {
+    assert!(validate_token_symbol("PUMP").is_ok());
+    assert!(validate_token_symbol("PUMP2").is_ok());
+    assert!(validate_token_symbol("").is_err());
+    assert!(validate_token_symbol(&"A".repeat(MAX_SYMBOL_LENGTH + 1)).is_err());
+    assert!(validate_token_symbol("PU MP").is_err());
+    assert!(validate_token_symbol("PUMP$").is_err());
+  }
 
+  #[test]
+  fn test_validate_token_uri() {
+    assert!(validate_token_uri("https://example.com/token.json").is_ok());
+    assert!(validate_token_uri("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi").is_ok());
+    assert!(validate_token_uri("ar://token").is_ok());
+    assert!(validate_token_uri("").is_err());
+    assert!(validate_token_uri("https://").is_err());
+    assert!(validate_token_uri("http://example.com").is_err());
+    assert!(validate_token_uri("URI123456789").is_err());
+    assert!(validate_token_uri("https://example.com/a b").is_err());
+    assert!(validate_token_uri(&format!("https://{}", "a".repeat(MAX_URI_LENGTH))).is_err());
+  }
+}
@@ -0,0 +1,210 @@
+use crate::*;
+
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Permissionless: buys one slice of a due DCA vault like `buy_in_sol`, tokens go to the owner.
+#[derive(Accounts)]
+pub struct ExecuteDca<'info> {
+  pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+  #[account(
+    mut,
+    seeds = [
+      CONFIG_SEED,
+    ],
+    bump = global_config.bump
+  )]
+  pub global_config: Box<Account<'info, GlobalConfig>>,
+
+  #[account(
+    mut,
+    seeds = [
+      BONDING_CURVE_SEED,
+      token_mint.key().as_ref()
+    ],
+    bump = bonding_curve.bump,
+  )]
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    mut,
+    seeds = [
+      CREATOR_VAULT_SEED,
+      bonding_curve.creator.as_ref()
+    ],
+    bump = creator_vault.bump,
+  )]
+  pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+  #[account(
+    mut,
+    seeds = [
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump = platform_fee_vault.bump
+  )]
+  pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+  #[account(
+    mut,
+    seeds = [
+      DCA_VAULT_SEED,
+      token_mint.key().as_ref(),
+      dca_vault.owner.as_ref()
+    ],
+    bump = dca_vault.bump,
+  )]
+  pub dca_vault: Box<Account<'info, DcaVault>>,
+
+  #[account(
+    mut,
+    associated_token::mint = token_mint,
+    associated_token::authority = bonding_curve,
+    token::token_program = token_program,
+  )]
+  pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    address = get_associated_token_address_with_program_id(
+      &dca_vault.owner,
+      &token_mint.key(),
+      &token_program.key()
+    ),
+  )]
+  pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  pub keeper: Signer<'info>,
+  pub token_program: Interface<'info, TokenInterface>,
+  pub system_program: Program<'info, System>,
+}
+
+impl ExecuteDca<'_> {
+  pub fn apply(ctx: &mut Context<ExecuteDca>) -> Result<()> {
+    let decimals = ctx.accounts.token_mint.decimals;
+    let clock = Clock::get()?;
+    ctx.accounts.bonding_curve.check_trading_open(&clock)?;
+    // launch caps are per wallet, keepers only fill once the window is over
+    require!(
+        ctx.accounts.bonding_curve.launch_window(clock.slot).is_none(),
+        PumpFunError::LaunchWindowOpen
+    );
+    require!(ctx.accounts.dca_vault.is_due(clock.unix_timestamp), PumpFunError::DcaNotDue);
+
+    // the last slice spends whatever is left above rent, dust below min_slice waits for cancel_dca
+    let vault_info = ctx.accounts.dca_vault.to_account_info();
+    let balance = vault_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(vault_info.data_len()));
+    // fees come out of the slice, the rest goes into the curve
+    let (sol, fees) = ctx.accounts.dca_vault.slice(
+        balance,
+        ctx.accounts.bonding_curve.creator_fee_bps,
+        ctx.accounts.global_config.fee_rate,
+    )?;
+    let sol_in = sol.checked_sub(fees.total()).ok_or(PumpFunError::MathOverflow)?;
+
+    // checked against the funding goal and the available supply
+    let vault_amount = ctx.accounts.associted_bonding_curve.amount;
+    let token_amount_to_purchased = buy_amount(vault_amount, sol_in, decimals)?;
+    require!(
+        token_amount_to_purchased <= available_supply(vault_amount)?,
+        PumpFunError::NotEnoughSuppply
+    );
+    require!(
+        price_at_least(token_amount_to_purchased, sol, 10u64.pow(decimals as u32), ctx.accounts.dca_vault.max_price),
+        PumpFunError::DcaMaxPriceExceeded
+    );
+
+    ctx.accounts.bonding_curve.check_holding(
+        ctx.accounts.owner_token_account.amount
+            .checked_add(token_amount_to_purchased)
+            .ok_or(PumpFunError::MathOverflow)?,
+    )?;
+
+    //transfer sol from the dca vault to the curve
+    transfer_sol_from_vault_to_user(
+        vault_info.clone(),
+        ctx.accounts.bonding_curve.to_account_info(),
+        sol_in,
+    )?;
+    //transfer fee
+    pay_trade_fees(
+        &vault_info,
+        &fees,
+        Some(&mut *ctx.accounts.creator_vault),
+        &ctx.accounts.platform_fee_vault.to_account_info(),
+        None,
+        &mut ctx.accounts.global_config,
+    )?;
+
+    //transfer token from vault to owner
+    let token_mint = ctx.accounts.token_mint.key();
+    let vault_seeds = &[
+        BONDING_CURVE_SEED,
+        token_mint.as_ref(),
+        &[ctx.accounts.bonding_curve.bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    transfer_token_from_vault_to_user(
+        ctx.accounts.bonding_curve.to_account_info(),
+        ctx.accounts.associted_bonding_curve.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        token_amount_to_purchased,
+        decimals,
+        vault_signer_seeds,
+    )?;
+    ctx.accounts.bonding_curve.record_buy(token_amount_to_purchased, sol_in)?;
+
+    let dca = &mut ctx.accounts.dca_vault;
+    dca.last_executed_ts = clock.unix_timestamp;
+    dca.total_spent = dca.total_spent.checked_add(sol).ok_or(PumpFunError::MathOverflow)?;
+    dca.total_tokens = dca
+        .total_tokens
+        .checked_add(token_amount_to_purchased)
+        .ok_or(PumpFunError::MathOverflow)?;
+
+    emit!(BuyEvent {
+        mint: token_mint,
+        token_output: token_amount_to_purchased,
+        sol_input: sol_in,
+        buyer: ctx.accounts.dca_vault.owner,
+        creator_fee: fees.creator_fee,
+        platform_fee: fees.platform_fee,
+        referral_fee: 0,
+        referrer: None,
+    });
+    emit!(ExecuteDcaEvent {
+        dca_vault: ctx.accounts.dca_vault.key(),
+        mint: token_mint,
+        owner: ctx.accounts.dca_vault.owner,
+        keeper: ctx.accounts.keeper.key(),
+        sol_amount: sol,
+        token_amount: token_amount_to_purchased,
+        remaining: balance - sol,
+    });
+
+    if BondingCurve::completes(vault_amount - token_amount_to_purchased) {
+        BondingCurve::complete(
+            &mut ctx.accounts.bonding_curve,
+            &mut ctx.accounts.global_config,
+            ctx.accounts.platform_fee_vault.to_account_info(),
+            Some(ctx.accounts.creator_vault.as_mut()),
+        )?;
+    }
+
+    ctx.accounts.associted_bonding_curve.reload()?;
+    ctx.accounts.bonding_curve.assert_invariants(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        ctx.accounts.associted_bonding_curve.amount,
+        decimals,
+    )
+  }
+
+}
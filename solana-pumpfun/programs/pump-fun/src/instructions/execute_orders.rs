@@ -0,0 +1,270 @@
+use crate::*;
+
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+// remaining accounts per order: limit_order, owner, owner_token_account, order_token_account
+pub const ORDER_ACCOUNTS_LEN: usize = 4;
+
+/// Permissionless: fills the given orders of one mint whose trigger price is crossed, orders that
+/// are not are skipped. Each filled order pays its tip to the executor and is closed.
+#[derive(Accounts)]
+pub struct ExecuteOrders<'info> {
+  pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+  #[account(
+    mut,
+    seeds = [
+      CONFIG_SEED,
+    ],
+    bump = global_config.bump
+  )]
+  pub global_config: Box<Account<'info, GlobalConfig>>,
+
+  #[account(
+    mut,
+    seeds = [
+      BONDING_CURVE_SEED,
+      token_mint.key().as_ref()
+    ],
+    bump = bonding_curve.bump,
+  )]
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    mut,
+    seeds = [
+      CREATOR_VAULT_SEED,
+      bonding_curve.creator.as_ref()
+    ],
+    bump = creator_vault.bump,
+  )]
+  pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+  #[account(
+    mut,
+    seeds = [
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump = platform_fee_vault.bump
+  )]
+  pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+  #[account(
+    mut,
+    associated_token::mint = token_mint,
+    associated_token::authority = bonding_curve,
+    token::token_program = token_program,
+  )]
+  pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(mut)]
+    pub executor: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteOrders<'info> {
+  pub fn apply(ctx: &mut Context<'_, '_, 'info, 'info, ExecuteOrders<'info>>) -> Result<()> {
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % ORDER_ACCOUNTS_LEN == 0,
+        PumpFunError::InvalidInput
+    );
+    let clock = Clock::get()?;
+    ctx.accounts.bonding_curve.check_trading_open(&clock)?;
+    // launch caps are per wallet, keepers only fill once the window is over
+    require!(
+        ctx.accounts.bonding_curve.launch_window(clock.slot).is_none(),
+        PumpFunError::LaunchWindowOpen
+    );
+
+    let remaining_accounts = ctx.remaining_accounts;
+    let mut filled = 0;
+    for accounts in remaining_accounts.chunks(ORDER_ACCOUNTS_LEN) {
+        if Self::execute_order(ctx, accounts)? {
+            filled += 1;
+        }
+    }
+    require!(filled > 0, PumpFunError::OrderNotTriggered);
+
+    ctx.accounts.associted_bonding_curve.reload()?;
+    ctx.accounts.bonding_curve.assert_invariants(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        ctx.accounts.associted_bonding_curve.amount,
+        ctx.accounts.token_mint.decimals,
+    )
+  }
+
+  // false when the order is skipped
+  fn execute_order(
+    ctx: &mut Context<'_, '_, 'info, 'info, ExecuteOrders<'info>>,
+    accounts: &'info [AccountInfo<'info>],
+  ) -> Result<bool> {
+    let [order_info, owner_info, owner_token_info, order_token_info] = accounts else {
+        return err!(PumpFunError::InvalidInput);
+    };
+    let order = Account::<LimitOrder>::try_from(order_info)?;
+    let token_mint = ctx.accounts.token_mint.key();
+    let token_program = ctx.accounts.token_program.key();
+    require!(
+        order.token_mint == token_mint
+            && order.owner == owner_info.key()
+            && owner_token_info.key() == get_associated_token_address_with_program_id(owner_info.key, &token_mint, &token_program)
+            && order_token_info.key() == get_associated_token_address_with_program_id(order_info.key, &token_mint, &token_program),
+        PumpFunError::InvalidLimitOrder
+    );
+
+    // not crossed, too small to fill after its fees or the curve completed before it,
+    // the order stays open and the rest of the batch goes on
+    let decimals = ctx.accounts.token_mint.decimals;
+    ctx.accounts.associted_bonding_curve.reload()?;
+    let vault_amount = ctx.accounts.associted_bonding_curve.amount;
+    let fill = if ctx.accounts.bonding_curve.complete {
+        None
+    } else {
+        order.fill(
+            vault_amount,
+            ctx.accounts.bonding_curve.creator_fee_bps,
+            ctx.accounts.global_config.fee_rate,
+            decimals,
+        )?
+    };
+    let Some(OrderFill { token_amount, curve_sol, fees }) = fill else {
+        emit!(LimitOrderSkippedEvent {
+            order: order_info.key(),
+            mint: token_mint,
+            owner: order.owner,
+            order_id: order.order_id,
+            executor: ctx.accounts.executor.key(),
+        });
+        return Ok(false);
+    };
+
+    let order_id = order.order_id.to_le_bytes();
+    let order_seeds = &[
+        LIMIT_ORDER_SEED,
+        token_mint.as_ref(),
+        order.owner.as_ref(),
+        &order_id,
+        &[order.bump],
+    ];
+    let order_signer_seeds = &[&order_seeds[..]];
+    let vault_seeds = &[
+        BONDING_CURVE_SEED,
+        token_mint.as_ref(),
+        &[ctx.accounts.bonding_curve.bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    let sol_amount = match order.side {
+      OrderSide::Buy => {
+        let balance = InterfaceAccount::<TokenAccount>::try_from(owner_token_info)?.amount;
+        ctx.accounts.bonding_curve.check_holding(
+            balance.checked_add(token_amount).ok_or(PumpFunError::MathOverflow)?,
+        )?;
+
+        transfer_sol_from_vault_to_user(order_info.clone(), ctx.accounts.bonding_curve.to_account_info(), curve_sol)?;
+        pay_trade_fees(
+            order_info,
+            &fees,
+            Some(&mut *ctx.accounts.creator_vault),
+            &ctx.accounts.platform_fee_vault.to_account_info(),
+            None,
+            &mut ctx.accounts.global_config,
+        )?;
+        transfer_token_from_vault_to_user(
+            ctx.accounts.bonding_curve.to_account_info(),
+            ctx.accounts.associted_bonding_curve.to_account_info(),
+            owner_token_info.clone(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            token_amount,
+            decimals,
+            vault_signer_seeds,
+        )?;
+        ctx.accounts.bonding_curve.record_buy(token_amount, curve_sol)?;
+        emit!(BuyEvent {
+            mint: token_mint,
+            token_output: token_amount,
+            sol_input: curve_sol,
+            buyer: order.owner,
+            creator_fee: fees.creator_fee,
+            platform_fee: fees.platform_fee,
+            referral_fee: 0,
+            referrer: None,
+        });
+
+        if BondingCurve::completes(vault_amount - token_amount) {
+            BondingCurve::complete(
+                &mut ctx.accounts.bonding_curve,
+                &mut ctx.accounts.global_config,
+                ctx.accounts.platform_fee_vault.to_account_info(),
+                Some(ctx.accounts.creator_vault.as_mut()),
+            )?;
+        }
+        order.amount
+      }
+      OrderSide::Sell => {
+        let sol_output = curve_sol - fees.total();
+
+        transfer_token_from_vault_to_user(
+            order_info.clone(),
+            order_token_info.clone(),
+            ctx.accounts.associted_bonding_curve.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            order.amount,
+            decimals,
+            order_signer_seeds,
+        )?;
+        ctx.accounts.bonding_curve.record_sell(order.amount, curve_sol)?;
+        let curve_info = ctx.accounts.bonding_curve.to_account_info();
+        transfer_sol_from_vault_to_user(curve_info.clone(), owner_info.clone(), sol_output)?;
+        pay_trade_fees(
+            &curve_info,
+            &fees,
+            Some(&mut *ctx.accounts.creator_vault),
+            &ctx.accounts.platform_fee_vault.to_account_info(),
+            None,
+            &mut ctx.accounts.global_config,
+        )?;
+        emit!(SellEvent {
+            mint: token_mint,
+            token_input: order.amount,
+            sol_output,
+            seller: order.owner,
+            creator_fee: fees.creator_fee,
+            platform_fee: fees.platform_fee,
+            referral_fee: 0,
+            referrer: None,
+        });
+        sol_output
+      }
+    };
+
+    // tip to the executor, token account rent and the rest of the order account to the owner
+    close_token_account_from_vault(
+        order_info.clone(),
+        order_token_info.clone(),
+        owner_info.clone(),
+        ctx.accounts.token_program.to_account_info(),
+        order_signer_seeds,
+    )?;
+    transfer_sol_from_vault_to_user(order_info.clone(), ctx.accounts.executor.to_account_info(), order.tip)?;
+    emit!(LimitOrderExecutedEvent {
+        order: order_info.key(),
+        mint: token_mint,
+        owner: order.owner,
+        order_id: order.order_id,
+        executor: ctx.accounts.executor.key(),
+        side: order.side,
+        token_amount,
+        sol_amount,
+        tip: order.tip,
+    });
+    order.close(owner_info.clone())?;
+    Ok(true)
+  }
+}
@@ -0,0 +1,56 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: FundPeerSolVaultParams)]
+pub struct FundPeerSolVault<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    #[account(
+        seeds = [PEER_SEED, &oapp_config.key().to_bytes(), &params.src_eid.to_be_bytes()],
+        bump = peer.bump
+    )]
+    pub peer: Box<Account<'info, Peer>>,
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + PeerSolVault::INIT_SPACE,
+        seeds = [PEER_SOL_VAULT_SEED, &oapp_config.key().to_bytes(), &params.src_eid.to_be_bytes()],
+        bump
+    )]
+    pub peer_sol_vault: Box<Account<'info, PeerSolVault>>,
+    #[account(
+        seeds = [OAPP_SEED],
+        bump = oapp_config.bump
+    )]
+    pub oapp_config: Box<Account<'info, OAppConfig>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl FundPeerSolVault<'_> {
+    pub fn apply(ctx: &mut Context<FundPeerSolVault>, params: &FundPeerSolVaultParams) -> Result<()> {
+        require!(params.amount > 0, PumpFunError::InvalidInput);
+        transfer_sol(
+            ctx.accounts.funder.to_account_info(),
+            ctx.accounts.peer_sol_vault.to_account_info(),
+            params.amount,
+        )?;
+
+        let vault = &mut ctx.accounts.peer_sol_vault;
+        vault.eid = params.src_eid;
+        vault.bump = ctx.bumps.peer_sol_vault;
+        vault.record_funded(params.amount)?;
+
+        emit!(PeerSolVaultFunded {
+            eid: params.src_eid,
+            funder: ctx.accounts.funder.key(),
+            amount: params.amount,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct FundPeerSolVaultParams {
+    pub src_eid: u32,
+    pub amount: u64,
+}
@@ -0,0 +1,34 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: GetCrossChainLogParams)]
+pub struct GetCrossChainLog<'info> {
+    #[account(
+        seeds = [OAPP_SEED],
+        bump = oapp_config.bump
+    )]
+    pub oapp_config: Box<Account<'info, OAppConfig>>,
+    #[account(
+        seeds = [CROSS_CHAIN_LOG_SEED, &oapp_config.key().to_bytes(), &params.eid.to_be_bytes()],
+        bump = cross_chain_log.bump
+    )]
+    pub cross_chain_log: Box<Account<'info, CrossChainLog>>,
+}
+
+impl GetCrossChainLog<'_> {
+    // newest first, at most CROSS_CHAIN_LOG_VIEW_MAX entries per call
+    pub fn apply(
+        ctx: &Context<GetCrossChainLog>,
+        params: &GetCrossChainLogParams,
+    ) -> Result<Vec<CrossChainLogEntry>> {
+        let limit = (params.limit as usize).min(CROSS_CHAIN_LOG_VIEW_MAX);
+        Ok(ctx.accounts.cross_chain_log.latest(params.offset as usize, limit))
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct GetCrossChainLogParams {
+    pub eid: u32,
+    pub offset: u16,
+    pub limit: u16,
+}
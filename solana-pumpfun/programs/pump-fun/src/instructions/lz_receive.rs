@@ -1,12 +1,11 @@
 use crate::*;
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken, Create},
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
-use anchor_lang::prelude::Rent;
 use oapp::endpoint::{
     cpi::accounts::Clear,
-    instructions::ClearParams,
+    instructions::{ClearParams, SendComposeParams},
     ConstructCPIContext,
 };
 
@@ -24,7 +23,8 @@ pub struct LzReceive<'info> {
             &params.src_eid.to_be_bytes()
         ],
         bump = peer.bump,
-        constraint = peer.address == params.sender @OftError::InvalidSender
+        constraint = peer.address == params.sender @OftError::InvalidSender,
+        constraint = peer.inbound_enabled @OftError::InboundDisabled
     )]
     pub peer: Box<Account<'info, Peer>>,
     #[account(
@@ -33,23 +33,26 @@ pub struct LzReceive<'info> {
     )]
     pub oapp_config: Box<Account<'info, OAppConfig>>,
 
-    #[account(address = Pubkey::from(msg_codec::get_meme_addr(&params.message)) @OftError::InvalidTokenMint)]
+    #[account(
+        mut,
+        address = Pubkey::from(msg_codec::get_meme_addr(&params.message)) @OftError::InvalidTokenMint,
+        mint::token_program = token_program,
+    )]
     pub token_mint: InterfaceAccount<'info, Mint>,
 
     /// CHECK: the wallet address to receive the token
     #[account(address = Pubkey::from(msg_codec::get_receipt_addr(&params.message)) @OftError::InvalidReceiver)]
     pub to_address: AccountInfo<'info>,
 
-    /// CHECK: token vault address
     #[account(
         mut,
         seeds = [
             BONDING_CURVE_SEED,
             token_mint.key().as_ref()
         ],
-        bump,
+        bump = bonding_curve.bump,
     )]
-    pub bonding_curve: UncheckedAccount<'info>,
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
 
     #[account(
         mut,
@@ -59,18 +62,68 @@ pub struct LzReceive<'info> {
     )]
     pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// CHECK: created in apply when missing, so the executor is only reimbursed for actual rent
     #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = token_mint,
-        associated_token::authority = to_address,
-        token::token_program = token_program,
-      )]
-      pub associted_user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &to_address.key(),
+            &token_mint.key(),
+            &token_program.key()
+        ) @OftError::InvalidTokenDest
+    )]
+    pub associted_user_token_account: UncheckedAccount<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    // only for buy msgs
+    #[account(
+        mut,
+        seeds = [
+            PEER_SOL_VAULT_SEED,
+            &oapp_config.key().to_bytes(),
+            &params.src_eid.to_be_bytes()
+        ],
+        bump = peer_sol_vault.bump,
+    )]
+    pub peer_sol_vault: Option<Box<Account<'info, PeerSolVault>>>,
+
+    // only for send token msgs
+    #[account(
+        mut,
+        seeds = [OFT_SEED, token_mint.key().as_ref()],
+        bump = oft_store.bump,
+    )]
+    pub oft_store: Option<Box<Account<'info, OftStore>>>,
+    #[account(mut)]
+    pub token_escrow: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: optional audit log, only written when initialized
+    #[account(
+        mut,
+        seeds = [
+            CROSS_CHAIN_LOG_SEED,
+            &oapp_config.key().to_bytes(),
+            &params.src_eid.to_be_bytes()
+        ],
+        bump,
+    )]
+    pub cross_chain_log: UncheckedAccount<'info>,
+
+    // graduation fee destination when an inbound buy completes the curve
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+    #[account(
+        mut,
+        seeds = [PLATFORM_FEE_VAULT_SEED],
+        bump = platform_fee_vault.bump
+    )]
+    pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
 }
 
 impl LzReceive<'_> {
@@ -95,62 +148,259 @@ impl LzReceive<'_> {
         )?;
 
         let is_buy = msg_codec::is_buy_token(&params.message);
-        let sol_amount = msg_codec::get_sol_amount(&params.message);
-        let decimals = 9;
-        if is_buy {
-            // check to ensure funding goal is not met
-            require!(
-                ctx.accounts.associted_bonding_curve.amount > INIT_SUPPLY,
-                PumpFunError::AlreadyRaised
-            );
-            let current_supply =
-                MAX_SUPPLY - ctx.accounts.associted_bonding_curve.amount;
-            let rent = Rent::get()?;
-            let token_account_size = 165; // SPL Token account size in bytes
-            let rent_exemption = rent.minimum_balance(token_account_size);
+        if !is_buy && !msg_codec::is_send_token(&params.message) {
+            return Self::record(ctx, params, 0, 0, MessageOutcome::Ignored);
+        }
+
+        // token amount credited to the receiver
+        let (sol_amount, amount_received_ld) = if is_buy {
+            Self::receive_buy(ctx, params)?
+        } else {
+            // the executor pays the receiver's token account rent, as for any OFT receive
+            Self::create_user_token_account(ctx)?;
+            (0, Self::receive_token(ctx, params)?)
+        };
+        Self::record(ctx, params, sol_amount, amount_received_ld, MessageOutcome::Executed)?;
+
+        // forward the compose msg to the receiver so it can act on the credited tokens
+        if let Some(message) = Self::compose_message(params) {
+            oapp::endpoint_cpi::send_compose(
+                ctx.accounts.oapp_config.endpoint_program,
+                ctx.accounts.oapp_config.key(),
+                &ctx.remaining_accounts[Clear::MIN_ACCOUNTS_LEN..],
+                seeds,
+                SendComposeParams {
+                    to: ctx.accounts.to_address.key(),
+                    guid: params.guid,
+                    index: 0,
+                    message,
+                },
+            )?;
+        }
+        Ok(())
+    }
 
-            let sol = sol_amount - rent_exemption;  //fee to create tokenAccount
-            let token_amount_to_purchased = calculate_token_amount(current_supply, sol, decimals);
-            let available_qty =
-                ctx.accounts.associted_bonding_curve.amount - INIT_SUPPLY;
+    fn record(
+        ctx: &Context<LzReceive>,
+        params: &LzReceiveParams,
+        sol_amount: u64,
+        token_amount: u64,
+        outcome: MessageOutcome,
+    ) -> Result<()> {
+        CrossChainLog::try_record(
+            &ctx.accounts.cross_chain_log.to_account_info(),
+            CrossChainLogEntry {
+                guid: params.guid,
+                direction: MessageDirection::Inbound,
+                msg_type: msg_codec::msg_type(&params.message),
+                mint: ctx.accounts.token_mint.key(),
+                recipient: ctx.accounts.to_address.key().to_bytes(),
+                native_amount: sol_amount as u128,
+                token_amount: token_amount as u128,
+                outcome,
+                timestamp: Clock::get()?.unix_timestamp,
+            },
+        )
+    }
 
-            require!(token_amount_to_purchased <= available_qty, PumpFunError::NotEnoughSuppply);
+    // returns the rent paid for the receiver's token account, 0 if it already existed
+    fn create_user_token_account(ctx: &mut Context<LzReceive>) -> Result<u64> {
+        if !ctx.accounts.associted_user_token_account.data_is_empty() {
+            return Ok(0);
+        }
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: ctx.accounts.payer.to_account_info(),
+                associated_token: ctx.accounts.associted_user_token_account.to_account_info(),
+                authority: ctx.accounts.to_address.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+        Ok(ctx.accounts.associted_user_token_account.lamports())
+    }
 
-            //transfer sol to vault
-            transfer_sol(
+    // buy with SOL from the peer vault, the remote chain already collected the payment,
+    // returns the SOL and token amounts of the buy
+    fn receive_buy(ctx: &mut Context<LzReceive>, params: &LzReceiveParams) -> Result<(u64, u64)> {
+        let sol = msg_codec::get_sol_amount(&params.message);
+        let vault_info = ctx
+            .accounts
+            .peer_sol_vault
+            .as_ref()
+            .ok_or(OftError::InsufficientLiquidity)?
+            .to_account_info();
+        // the executor pays the receiver's token account rent and gets it back from the peer vault
+        let rent_reimbursed = Self::create_user_token_account(ctx)?;
+        require!(
+            ctx.accounts.peer_sol_vault.as_ref().unwrap().available(&vault_info, &Rent::get()?)
+                >= sol.checked_add(rent_reimbursed).ok_or(PumpFunError::MathOverflow)?,
+            OftError::InsufficientLiquidity
+        );
+        if rent_reimbursed > 0 {
+            transfer_sol_from_vault_to_user(
+                vault_info.clone(),
                 ctx.accounts.payer.to_account_info(),
-                ctx.accounts.bonding_curve.to_account_info(),
-                sol,
+                rent_reimbursed,
             )?;
-            //transfer fee
-
-            //transfer token from vault to user
-            let token_mint = ctx.accounts.token_mint.key();
-            let vault_seeds = &[
-                BONDING_CURVE_SEED,
-                token_mint.as_ref(),
-                &[ctx.bumps.bonding_curve],
-            ];
-            let vault_signer_seeds = &[&vault_seeds[..]];
-
-            transfer_token_from_vault_to_user(
-                ctx.accounts.bonding_curve.to_account_info(),
-                ctx.accounts.associted_bonding_curve.to_account_info(),
-                ctx.accounts.associted_user_token_account.to_account_info(),
-                ctx.accounts.token_mint.to_account_info(),
-                ctx.accounts.token_program.to_account_info(),
-                token_amount_to_purchased,
-                decimals,
-                vault_signer_seeds,
+        }
+
+        let decimals = ctx.accounts.token_mint.decimals;
+        // fails until the scheduled start, the message can be retried afterwards
+        ctx.accounts.bonding_curve.check_trading_open(&Clock::get()?)?;
+        let vault_amount = ctx.accounts.associted_bonding_curve.amount;
+        // the creator vault is not derivable in lz_receive_types, the fee waits on the curve
+        let creator_fee = calculate_fee(sol, ctx.accounts.bonding_curve.creator_fee_bps);
+        let sol_in = sol.checked_sub(creator_fee).ok_or(PumpFunError::MathOverflow)?;
+        // checked against the funding goal and the available supply
+        let token_amount_to_purchased = buy_amount(vault_amount, sol_in, decimals)?;
+        require!(
+            token_amount_to_purchased <= available_supply(vault_amount)?,
+            PumpFunError::NotEnoughSuppply
+        );
+        // the receiver's token account exists at this point
+        let balance = TokenAccount::try_deserialize(
+            &mut &ctx.accounts.associted_user_token_account.try_borrow_data()?[..],
+        )?
+        .amount;
+        ctx.accounts.bonding_curve.check_holding(
+            balance
+                .checked_add(token_amount_to_purchased)
+                .ok_or(PumpFunError::MathOverflow)?,
+        )?;
+        // no per-wallet tally for remote buyers, an oversized buy can be retried once the window closes
+        if let Some(protection) = ctx.accounts.bonding_curve.launch_window(Clock::get()?.slot) {
+            require!(
+                token_amount_to_purchased <= protection.max_buy_per_tx,
+                PumpFunError::LaunchTxCapExceeded
+            );
+        }
+
+        //transfer sol from peer vault to bonding curve vault
+        transfer_sol_from_vault_to_user(
+            vault_info,
+            ctx.accounts.bonding_curve.to_account_info(),
+            sol,
+        )?;
+        //transfer fee
+        let curve = &mut ctx.accounts.bonding_curve;
+        curve.creator_fees_unswept = curve
+            .creator_fees_unswept
+            .checked_add(creator_fee)
+            .ok_or(PumpFunError::MathOverflow)?;
+
+        //transfer token from vault to user
+        let token_mint = ctx.accounts.token_mint.key();
+        let vault_seeds = &[
+            BONDING_CURVE_SEED,
+            token_mint.as_ref(),
+            &[ctx.accounts.bonding_curve.bump],
+        ];
+        let vault_signer_seeds = &[&vault_seeds[..]];
+
+        transfer_token_from_vault_to_user(
+            ctx.accounts.bonding_curve.to_account_info(),
+            ctx.accounts.associted_bonding_curve.to_account_info(),
+            ctx.accounts.associted_user_token_account.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            token_amount_to_purchased,
+            decimals,
+            vault_signer_seeds,
+        )?;
+        ctx.accounts.bonding_curve.record_buy(token_amount_to_purchased, sol_in)?;
+        emit!(BuyEvent {
+            mint: ctx.accounts.token_mint.key(),
+            token_output: token_amount_to_purchased,
+            sol_input: sol_in,
+            buyer: ctx.accounts.to_address.key(),
+            creator_fee,
+            // platform fee is collected with the payment on the remote chain
+            platform_fee: 0,
+            referral_fee: 0,
+            referrer: None,
+        });
+
+        if BondingCurve::completes(vault_amount - token_amount_to_purchased) {
+            BondingCurve::complete(
+                &mut ctx.accounts.bonding_curve,
+                &mut ctx.accounts.global_config,
+                ctx.accounts.platform_fee_vault.to_account_info(),
+                None,
             )?;
-            emit!(BuyEvent {
-                mint: ctx.accounts.token_mint.key(),
-                token_output: token_amount_to_purchased,
-                sol_input: sol,
-                buyer: ctx.accounts.to_address.key()
-            });
         }
-        Ok(())
+
+        ctx.accounts.associted_bonding_curve.reload()?;
+        ctx.accounts.bonding_curve.assert_invariants(
+            &ctx.accounts.bonding_curve.to_account_info(),
+            ctx.accounts.associted_bonding_curve.amount,
+            decimals,
+        )?;
+
+        let vault = ctx.accounts.peer_sol_vault.as_mut().unwrap();
+        vault.record_spent(sol, rent_reimbursed)?;
+        emit!(PeerSolVaultSpent {
+            eid: params.src_eid,
+            guid: params.guid,
+            mint: ctx.accounts.token_mint.key(),
+            executor: ctx.accounts.payer.key(),
+            sol_amount: sol,
+            rent_reimbursed,
+        });
+        Ok((sol, token_amount_to_purchased))
+    }
+
+    // credit bridged tokens of a graduated meme token
+    fn receive_token(ctx: &mut Context<LzReceive>, params: &LzReceiveParams) -> Result<u64> {
+        let accounts = &mut ctx.accounts;
+        let oft_store = accounts.oft_store.as_mut().ok_or(OftError::InvalidTokenMint)?;
+        let amount_sd = msg_codec::get_token_amount(&params.message);
+        let amount_received_ld = oft_store.sd2ld(amount_sd)?;
+        let token_mint = accounts.token_mint.key();
+
+        // unlock from the escrow
+        let token_escrow = accounts.token_escrow.as_ref().ok_or(OftError::InvalidTokenEscrow)?;
+        require!(
+            token_escrow.key() == oft_store.token_escrow,
+            OftError::InvalidTokenEscrow
+        );
+        oft_store.tvl_ld = oft_store
+            .tvl_ld
+            .checked_sub(amount_received_ld)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        let oft_seeds = &[OFT_SEED, token_mint.as_ref(), &[oft_store.bump]];
+        transfer_token_from_vault_to_user(
+            oft_store.to_account_info(),
+            token_escrow.to_account_info(),
+            accounts.associted_user_token_account.to_account_info(),
+            accounts.token_mint.to_account_info(),
+            accounts.token_program.to_account_info(),
+            amount_received_ld,
+            accounts.token_mint.decimals,
+            &[&oft_seeds[..]],
+        )?;
+
+        emit!(OFTReceived {
+            guid: params.guid,
+            src_eid: params.src_eid,
+            to: accounts.to_address.key(),
+            amount_received_ld,
+        });
+        Ok(amount_received_ld)
+    }
+
+    // the compose msg lz_receive_types derives the compose accounts from, None without a compose payload
+    pub fn compose_message(params: &LzReceiveParams) -> Option<Vec<u8>> {
+        msg_codec::compose_msg(&params.message).map(|message| {
+            compose_msg_codec::encode(
+                params.nonce,
+                params.src_eid,
+                msg_codec::compose_amount(&params.message),
+                &message,
+            )
+        })
     }
 }
 
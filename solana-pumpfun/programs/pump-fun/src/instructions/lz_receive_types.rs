@@ -3,6 +3,7 @@ use anchor_lang::solana_program;
 use anchor_spl::{
     associated_token::{get_associated_token_address_with_program_id, ID as ASSOCIATED_TOKEN_ID},
     token::ID as TOKEN_ID,
+    token_2022::ID as TOKEN_2022_ID,
 };
 use oapp::endpoint_cpi::LzAccount;
 
@@ -27,11 +28,18 @@ pub struct LzReceiveTypes<'info> {
 // account 8 - token program
 // account 9 - associate token program
 // account 10 - system program
+// account 11 - peer sol vault (buy msgs only)
+// account 12 - oft store (send token msgs only)
+// account 13 - oft store's token escrow (send token msgs only)
+// account 14 - cross chain log
+// account 15 - global config
+// account 16 - platform fee vault
 
-// account 11 - event authority
-// account 12 - this program
+// account 17 - event authority
+// account 18 - this program
 // account remaining accounts
 //  0..9 - accounts for clear
+//  9..16 - accounts for compose (only if the message carries a compose msg)
 
 impl LzReceiveTypes<'_> {
     pub fn apply(
@@ -63,6 +71,11 @@ impl LzReceiveTypes<'_> {
 
         // account 3..10
         let token_mint = Pubkey::from(msg_codec::get_meme_addr(&params.message));
+        let token_program = if msg_codec::is_token_2022(&params.message) {
+            TOKEN_2022_ID
+        } else {
+            TOKEN_ID
+        };
         let to_address = Pubkey::from(msg_codec::get_receipt_addr(&params.message));
         let (bonding_curve, _) = Pubkey::find_program_address(
             &[BONDING_CURVE_SEED, &token_mint.to_bytes()],
@@ -71,30 +84,78 @@ impl LzReceiveTypes<'_> {
         let associted_bonding_curve = get_associated_token_address_with_program_id(
             &bonding_curve,
             &token_mint,
-            &TOKEN_ID,
+            &token_program,
         );
         let associted_user_token_account = get_associated_token_address_with_program_id(
             &to_address,
             &token_mint,
-            &TOKEN_ID,
+            &token_program,
         );
         accounts.extend_from_slice(&[
-            LzAccount { pubkey: token_mint, is_signer: false, is_writable: false }, // 3
+            LzAccount { pubkey: token_mint, is_signer: false, is_writable: true }, // 3
             LzAccount { pubkey: to_address, is_signer: false, is_writable: false }, // 4
             LzAccount { pubkey: bonding_curve, is_signer: false, is_writable: true }, // 5
             LzAccount { pubkey: associted_bonding_curve, is_signer: false, is_writable: true}, // 6
             LzAccount { pubkey: associted_user_token_account, is_signer: false, is_writable: true}, // 7
-            LzAccount { pubkey: TOKEN_ID, is_signer: false, is_writable: false }, // 8
+            LzAccount { pubkey: token_program, is_signer: false, is_writable: false }, // 8
             LzAccount { pubkey: ASSOCIATED_TOKEN_ID, is_signer: false, is_writable: false }, // 9
             LzAccount { pubkey: solana_program::system_program::ID, is_signer: false, is_writable: false }, // 10
         ]);
 
-        // account 11..
+        // account 11..13, optional accounts are set to this program when unused
+        if msg_codec::is_buy_token(&params.message) {
+            let (peer_sol_vault, _) = Pubkey::find_program_address(
+                &[PEER_SOL_VAULT_SEED, &oapp_info.key().to_bytes(), &params.src_eid.to_be_bytes()],
+                ctx.program_id,
+            );
+            accounts.push(LzAccount { pubkey: peer_sol_vault, is_signer: false, is_writable: true }); // 11
+        } else {
+            accounts.push(LzAccount { pubkey: ctx.program_id.key(), is_signer: false, is_writable: false }); // 11
+        }
+
+        if msg_codec::is_send_token(&params.message) {
+            let (oft_store, _) = Pubkey::find_program_address(
+                &[OFT_SEED, &token_mint.to_bytes()],
+                ctx.program_id,
+            );
+            let token_escrow = get_associated_token_address_with_program_id(
+                &oft_store,
+                &token_mint,
+                &token_program,
+            );
+            accounts.extend_from_slice(&[
+                LzAccount { pubkey: oft_store, is_signer: false, is_writable: true }, // 12
+                LzAccount { pubkey: token_escrow, is_signer: false, is_writable: true }, // 13
+            ]);
+        } else {
+            accounts.extend_from_slice(&[
+                LzAccount { pubkey: ctx.program_id.key(), is_signer: false, is_writable: false }, // 12
+                LzAccount { pubkey: ctx.program_id.key(), is_signer: false, is_writable: false }, // 13
+            ]);
+        }
+
+        // account 14
+        let (cross_chain_log, _) = Pubkey::find_program_address(
+            &[CROSS_CHAIN_LOG_SEED, &oapp_info.key().to_bytes(), &params.src_eid.to_be_bytes()],
+            ctx.program_id,
+        );
+        accounts.push(LzAccount { pubkey: cross_chain_log, is_signer: false, is_writable: true }); // 14
+
+        // account 15..16
+        let (global_config, _) = Pubkey::find_program_address(&[CONFIG_SEED], ctx.program_id);
+        let (platform_fee_vault, _) =
+            Pubkey::find_program_address(&[PLATFORM_FEE_VAULT_SEED], ctx.program_id);
+        accounts.extend_from_slice(&[
+            LzAccount { pubkey: global_config, is_signer: false, is_writable: true }, // 15
+            LzAccount { pubkey: platform_fee_vault, is_signer: false, is_writable: true }, // 16
+        ]);
+
+        // account 17..
         let (event_authority_account, _) =
             Pubkey::find_program_address(&[oapp::endpoint_cpi::EVENT_SEED], &ctx.program_id);
         accounts.extend_from_slice(&[
-            LzAccount { pubkey: event_authority_account, is_signer: false, is_writable: false }, // 11
-            LzAccount { pubkey: ctx.program_id.key(), is_signer: false, is_writable: false }, // 12
+            LzAccount { pubkey: event_authority_account, is_signer: false, is_writable: false }, // 17
+            LzAccount { pubkey: ctx.program_id.key(), is_signer: false, is_writable: false }, // 18
         ]);
 
         let endpoint_program = ctx.accounts.oapp_config.endpoint_program;
@@ -108,7 +169,93 @@ impl LzReceiveTypes<'_> {
         );
         accounts.extend(accounts_for_clear);
 
+        // remaining accounts 9..16
+        accounts.extend(Self::compose_accounts(endpoint_program, &oapp_info.key(), params));
 
         Ok(accounts)
     }
+
+    // send_compose accounts for the compose msg lz_receive forwards, empty without one
+    pub fn compose_accounts(
+        endpoint_program: Pubkey,
+        oapp_config: &Pubkey,
+        params: &LzReceiveParams,
+    ) -> Vec<LzAccount> {
+        if !msg_codec::is_buy_token(&params.message) && !msg_codec::is_send_token(&params.message) {
+            return Vec::new();
+        }
+        match LzReceive::compose_message(params) {
+            Some(message) => oapp::endpoint_cpi::get_accounts_for_send_compose(
+                endpoint_program,
+                oapp_config,
+                &Pubkey::from(msg_codec::get_receipt_addr(&params.message)),
+                &params.guid,
+                0,
+                &message,
+            ),
+            None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(msg_type: u8, compose_msg: Option<Vec<u8>>) -> LzReceiveParams {
+        LzReceiveParams {
+            src_eid: 30101,
+            sender: [1; 32],
+            nonce: 7,
+            guid: [2; 32],
+            message: msg_codec::encode(
+                msg_type,
+                [3; 32],
+                [4; 32],
+                1_000_000_000,
+                5_000_000,
+                Pubkey::new_unique(),
+                &compose_msg,
+            ),
+            extra_data: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_compose_accounts_match_lz_receive() {
+        let endpoint_program = Pubkey::new_unique();
+        let oapp_config = Pubkey::new_unique();
+        for (msg_type, amount) in [(1, 1_000_000_000), (3, 5_000_000_000)] {
+            let params = params(msg_type, Some(vec![9; 40]));
+            // what lz_receive hands to send_compose, the amount comes from the inbound msg
+            let message = LzReceive::compose_message(&params).unwrap();
+            assert_eq!(msg_codec::compose_amount(&params.message), amount);
+            assert_eq!(message[12..20], amount.to_be_bytes());
+
+            let expected: Vec<Pubkey> = oapp::endpoint_cpi::get_accounts_for_send_compose(
+                endpoint_program,
+                &oapp_config,
+                &Pubkey::from([4; 32]),
+                &params.guid,
+                0,
+                &message,
+            )
+            .iter()
+            .map(|account| account.pubkey)
+            .collect();
+            let derived: Vec<Pubkey> =
+                LzReceiveTypes::compose_accounts(endpoint_program, &oapp_config, &params)
+                    .iter()
+                    .map(|account| account.pubkey)
+                    .collect();
+            assert!(!derived.is_empty());
+            assert_eq!(derived, expected);
+        }
+
+        let no_compose = params(1, None);
+        assert!(LzReceiveTypes::compose_accounts(endpoint_program, &oapp_config, &no_compose).is_empty());
+        // sell msgs are ignored by lz_receive, nothing is composed
+        let sell = params(2, Some(vec![9; 40]));
+        assert!(LzReceiveTypes::compose_accounts(endpoint_program, &oapp_config, &sell).is_empty());
+    }
 }
@@ -1,26 +1,64 @@
 pub mod admin;
 pub mod buy;
 pub mod buy_in_sol;
+pub mod buy_many;
+pub mod buy_presale;
 pub mod create_token;
 pub mod init_oapp;
 pub mod sell;
+pub mod swap;
+pub mod update_token_metadata;
+pub mod update_trading_start;
+pub mod claim_creator_fees;
+pub mod register_referrer;
+pub mod claim_referral_fees;
+pub mod place_limit_order;
+pub mod cancel_limit_order;
+pub mod execute_orders;
+pub mod create_dca;
+pub mod execute_dca;
+pub mod cancel_dca;
 
 pub mod lz_receive;
 pub mod lz_receive_types;
 pub mod set_peer;
+pub mod set_peer_enabled;
+pub mod close_peer;
+pub mod fund_peer_sol_vault;
+pub mod get_cross_chain_log;
 pub mod quote;
 pub mod send;
+pub mod send_token;
 
 pub use admin::*;
 pub use buy::*;
 pub use buy_in_sol::*;
+pub use buy_many::*;
+pub use buy_presale::*;
 pub use create_token::*;
 pub use init_oapp::*;
 pub use sell::*;
+pub use swap::*;
+pub use update_token_metadata::*;
+pub use update_trading_start::*;
+pub use claim_creator_fees::*;
+pub use register_referrer::*;
+pub use claim_referral_fees::*;
+pub use place_limit_order::*;
+pub use cancel_limit_order::*;
+pub use execute_orders::*;
+pub use create_dca::*;
+pub use execute_dca::*;
+pub use cancel_dca::*;
 
 pub use lz_receive::*;
 pub use lz_receive_types::*;
 
 pub use set_peer::*;
+pub use set_peer_enabled::*;
+pub use close_peer::*;
+pub use fund_peer_sol_vault::*;
+pub use get_cross_chain_log::*;
 pub use quote::*;
-pub use send::*;
\ No newline at end of file
+pub use send::*;
+pub use send_token::*;
\ No newline at end of file
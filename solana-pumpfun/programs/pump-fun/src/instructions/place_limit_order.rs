@@ -0,0 +1,123 @@
+use crate::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Deposits SOL (buy) or tokens (sell) plus the executor tip into a new order of the owner.
+#[derive(Accounts)]
+#[instruction(params: PlaceLimitOrderParams)]
+pub struct PlaceLimitOrder<'info> {
+  pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+  #[account(
+    seeds = [
+      BONDING_CURVE_SEED,
+      token_mint.key().as_ref()
+    ],
+    bump = bonding_curve.bump,
+  )]
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    init,
+    payer = owner,
+    space = 8 + LimitOrder::INIT_SPACE,
+    seeds = [
+      LIMIT_ORDER_SEED,
+      token_mint.key().as_ref(),
+      owner.key().as_ref(),
+      &params.order_id.to_le_bytes()
+    ],
+    bump,
+  )]
+  pub limit_order: Box<Account<'info, LimitOrder>>,
+
+  // holds the tokens of a sell order, closed when the order is executed or cancelled
+  #[account(
+    init,
+    associated_token::mint = token_mint,
+    associated_token::authority = limit_order,
+    token::token_program = token_program,
+    payer = owner,
+  )]
+  pub order_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    init_if_needed,
+    associated_token::mint = token_mint,
+    associated_token::authority = owner,
+    token::token_program = token_program,
+    payer = owner,
+  )]
+  pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(mut)]
+    pub owner: Signer<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl PlaceLimitOrder<'_> {
+  pub fn apply(ctx: &mut Context<PlaceLimitOrder>, params: &PlaceLimitOrderParams) -> Result<()> {
+    require!(params.amount > 0 && params.trigger_price > 0, PumpFunError::InvalidInput);
+    require!(!ctx.accounts.bonding_curve.complete, PumpFunError::AlreadyRaised);
+
+    **ctx.accounts.limit_order = LimitOrder::new(
+        ctx.accounts.token_mint.key(),
+        ctx.accounts.owner.key(),
+        ctx.bumps.limit_order,
+        params,
+    );
+
+    // the tip waits on the order account next to its rent
+    let deposit = match params.side {
+      OrderSide::Buy => params.amount.checked_add(params.tip).ok_or(PumpFunError::MathOverflow)?,
+      OrderSide::Sell => {
+        transfer_token_from_user_to_vault(
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.owner_token_account.to_account_info(),
+            ctx.accounts.order_token_account.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            params.amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+        params.tip
+      }
+    };
+    if deposit > 0 {
+      transfer_sol(
+          ctx.accounts.owner.to_account_info(),
+          ctx.accounts.limit_order.to_account_info(),
+          deposit,
+      )?;
+    }
+
+    emit!(LimitOrderPlacedEvent {
+        order: ctx.accounts.limit_order.key(),
+        mint: ctx.accounts.token_mint.key(),
+        owner: ctx.accounts.owner.key(),
+        order_id: params.order_id,
+        side: params.side,
+        amount: params.amount,
+        trigger_price: params.trigger_price,
+        tip: params.tip,
+    });
+    Ok(())
+  }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct PlaceLimitOrderParams {
+  // any unused id of the owner for this mint
+  pub order_id: u64,
+  pub side: OrderSide,
+  pub amount: u64,
+  // lamports per whole token, fees included
+  pub trigger_price: u64,
+  // lamports paid to whoever executes the order
+  pub tip: u64,
+}
@@ -15,7 +15,8 @@ pub struct Quote<'info> {
       oapp_config.key().as_ref(),
       &params.dst_eid.to_be_bytes()
     ],
-    bump = peer.bump
+    bump = peer.bump,
+    constraint = peer.outbound_enabled @OftError::OutboundDisabled
   )]
   pub peer: Account<'info, Peer>,
 }
@@ -0,0 +1,38 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+  #[account(mut)]
+  pub authority: Signer<'info>,
+
+  #[account(
+    init,
+    seeds = [
+      REFERRER_SEED,
+      authority.key().as_ref()
+    ],
+    payer = authority,
+    space = 8 + Referrer::INIT_SPACE,
+    bump,
+  )]
+  pub referrer: Box<Account<'info, Referrer>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+impl RegisterReferrer<'_> {
+  pub fn apply(ctx: &mut Context<RegisterReferrer>) -> Result<()> {
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.authority = ctx.accounts.authority.key();
+    referrer.bump = ctx.bumps.referrer;
+    referrer.total_accrued = 0;
+    referrer.total_claimed = 0;
+    referrer.trade_count = 0;
+
+    emit!(RegisterReferrerEvent {
+      authority: ctx.accounts.authority.key(),
+      referrer: ctx.accounts.referrer.key(),
+    });
+    Ok(())
+  }
+}
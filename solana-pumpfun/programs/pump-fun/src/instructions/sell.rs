@@ -1,8 +1,7 @@
 use crate::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::Token,
-    token_interface::{Mint, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 
@@ -11,6 +10,7 @@ pub struct Sell<'info> {
   pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
   #[account(
+    mut,
     seeds = [
       CONFIG_SEED,
     ],
@@ -19,16 +19,46 @@ pub struct Sell<'info> {
   pub global_config: Box<Account<'info, GlobalConfig>>,
 
 
-  /// CHECK
   #[account(
     mut,
     seeds = [
       BONDING_CURVE_SEED,
       token_mint.key().as_ref()
     ],
-    bump,
+    bump = bonding_curve.bump,
+  )]
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    mut,
+    seeds = [
+      CREATOR_VAULT_SEED,
+      bonding_curve.creator.as_ref()
+    ],
+    bump = creator_vault.bump,
+  )]
+  pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+  #[account(
+    mut,
+    seeds = [
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump = platform_fee_vault.bump
   )]
-  pub bonding_curve: UncheckedAccount<'info>,
+  pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+  // optional, takes referral_fee_bps of the platform fee
+  #[account(
+    mut,
+    seeds = [
+      REFERRER_SEED,
+      referrer.authority.as_ref()
+    ],
+    bump = referrer.bump,
+    constraint = referrer.authority != user.key() @PumpFunError::SelfReferral,
+  )]
+  pub referrer: Option<Box<Account<'info, Referrer>>>,
 
   #[account(
     mut,
@@ -49,7 +79,7 @@ pub struct Sell<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 impl Sell<'_> {
@@ -59,6 +89,15 @@ impl Sell<'_> {
   ) -> Result<()> {
     let decimals = ctx.accounts.token_mint.decimals;
 
+    // SOL of a completed curve is kept for migration
+    require!(!ctx.accounts.bonding_curve.complete, PumpFunError::AlreadyRaised);
+    ctx.accounts.bonding_curve.check_trading_open(&Clock::get()?)?;
+
+    // priced on the supply before the tokens come back
+    let sol_amount = sell_proceeds(ctx.accounts.associted_bonding_curve.amount, amount, decimals)?;
+    let fees = Self::fees(ctx, sol_amount);
+    let sol_output = sol_amount.checked_sub(fees.total()).ok_or(PumpFunError::MathOverflow)?;
+
     // transfer token from user to vault
     transfer_token_from_user_to_vault(
         ctx.accounts.user.to_account_info(), //authority
@@ -69,24 +108,49 @@ impl Sell<'_> {
         amount,
         ctx.accounts.token_mint.decimals,
     )?;
-    let current_supply =
-        MAX_SUPPLY - ctx.accounts.associted_bonding_curve.amount;
-
-    let sol_amount = calculate_cost(current_supply - amount, amount, decimals);
+    ctx.accounts.bonding_curve.record_sell(amount, sol_amount)?;
 
     //transfer sol from vault to user
     transfer_sol_from_vault_to_user(
         ctx.accounts.bonding_curve.to_account_info(),
         ctx.accounts.user.to_account_info(),
-        sol_amount,
+        sol_output,
+    )?;
+    //transfer fee
+    pay_trade_fees(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        &fees,
+        Some(&mut *ctx.accounts.creator_vault),
+        &ctx.accounts.platform_fee_vault.to_account_info(),
+        ctx.accounts.referrer.as_deref_mut(),
+        &mut ctx.accounts.global_config,
     )?;
     emit!(SellEvent {
         mint: ctx.accounts.token_mint.key(),
         token_input: amount,
-        sol_output: sol_amount,
-        seller: ctx.accounts.user.key()
+        sol_output,
+        seller: ctx.accounts.user.key(),
+        creator_fee: fees.creator_fee,
+        platform_fee: fees.platform_fee,
+        referral_fee: fees.referral_fee,
+        referrer: ctx.accounts.referrer.as_ref().map(|referrer| referrer.authority),
     });
-    Ok(())
+
+    ctx.accounts.associted_bonding_curve.reload()?;
+    ctx.accounts.bonding_curve.assert_invariants(
+        &ctx.accounts.bonding_curve.to_account_info(),
+        ctx.accounts.associted_bonding_curve.amount,
+        decimals,
+    )
+  }
+
+  pub fn fees(ctx: &Context<Sell>, sol_amount: u64) -> TradeFees {
+    TradeFees::new(
+      sol_amount,
+      ctx.accounts.bonding_curve.creator_fee_bps,
+      ctx.accounts.global_config.fee_rate,
+      ctx.accounts.referrer.as_ref().map(|_| ctx.accounts.global_config.referral_fee_bps),
+    )
   }
 }
 
@@ -13,7 +13,8 @@ pub struct Send<'info> {
       oapp_config.key().as_ref(),
       &params.dst_eid.to_be_bytes()
     ],
-    bump = peer.bump
+    bump = peer.bump,
+    constraint = peer.outbound_enabled @OftError::OutboundDisabled
   )]
   pub peer: Account<'info, Peer>,
   #[account(
@@ -22,6 +23,17 @@ pub struct Send<'info> {
     bump = oapp_config.bump
   )]
   pub oapp_config: Account<'info, OAppConfig>,
+  /// CHECK: optional audit log, only written when initialized
+  #[account(
+    mut,
+    seeds = [
+      CROSS_CHAIN_LOG_SEED,
+      oapp_config.key().as_ref(),
+      &params.dst_eid.to_be_bytes()
+    ],
+    bump
+  )]
+  pub cross_chain_log: UncheckedAccount<'info>,
 }
 
 impl Send<'_> {
@@ -61,6 +73,21 @@ impl Send<'_> {
         lz_token_fee: params.lz_token_fee,
       },
     )?;
+
+    CrossChainLog::try_record(
+      &ctx.accounts.cross_chain_log.to_account_info(),
+      CrossChainLogEntry {
+        guid: msg_receipt.guid,
+        direction: MessageDirection::Outbound,
+        msg_type: params.msg_type,
+        mint: Pubkey::from(params.token_addr),
+        recipient: params.to_addr,
+        native_amount: params.eth_amount,
+        token_amount: params.token_amount,
+        outcome: MessageOutcome::Sent,
+        timestamp: Clock::get()?.unix_timestamp,
+      },
+    )?;
     Ok(msg_receipt)
   }
 }
@@ -0,0 +1,177 @@
+use crate::*;
+use anchor_spl::{
+  token_2022::ID as TOKEN_2022_ID,
+  token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use oapp::endpoint::{
+  cpi::accounts::Send as EndpointSend, instructions::SendParams as EndpointSendParams,
+  ConstructCPIContext, MessagingReceipt,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SendTokenParams)]
+pub struct SendToken<'info> {
+  pub signer: Signer<'info>,
+  #[account(
+    mut,
+    seeds = [
+      PEER_SEED,
+      oapp_config.key().as_ref(),
+      &params.dst_eid.to_be_bytes()
+    ],
+    bump = peer.bump,
+    constraint = peer.outbound_enabled @OftError::OutboundDisabled,
+    constraint = peer.token_transfers_enabled @OftError::TokenTransfersDisabled
+  )]
+  pub peer: Account<'info, Peer>,
+  #[account(
+    mut,
+    seeds = [OAPP_SEED],
+    bump = oapp_config.bump
+  )]
+  pub oapp_config: Account<'info, OAppConfig>,
+  #[account(
+    mut,
+    seeds = [OFT_SEED, token_mint.key().as_ref()],
+    bump = oft_store.bump,
+    has_one = token_mint @OftError::InvalidTokenMint,
+    has_one = token_escrow @OftError::InvalidTokenEscrow
+  )]
+  pub oft_store: Account<'info, OftStore>,
+  #[account(
+    mut,
+    token::authority = signer,
+    token::mint = token_mint,
+    token::token_program = token_program,
+  )]
+  pub token_source: InterfaceAccount<'info, TokenAccount>,
+  #[account(
+    mut,
+    token::token_program = token_program,
+  )]
+  pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+  #[account(
+    mut,
+    mint::token_program = token_program,
+  )]
+  pub token_mint: InterfaceAccount<'info, Mint>,
+  pub token_program: Interface<'info, TokenInterface>,
+  /// CHECK: optional audit log, only written when initialized
+  #[account(
+    mut,
+    seeds = [
+      CROSS_CHAIN_LOG_SEED,
+      oapp_config.key().as_ref(),
+      &params.dst_eid.to_be_bytes()
+    ],
+    bump
+  )]
+  pub cross_chain_log: UncheckedAccount<'info>,
+}
+
+impl SendToken<'_> {
+  pub fn apply(
+      ctx: &mut Context<SendToken>,
+      params: &SendTokenParams,
+  ) -> Result<MessagingReceipt> {
+    require!(
+      ctx.remaining_accounts.len() >= EndpointSend::MIN_ACCOUNTS_LEN,
+      OftError::InvalidEndpointAccounts
+    );
+    require!(
+      ctx.accounts.oapp_config.key() == ctx.remaining_accounts[1].key(),
+      OftError::InvalidSender
+    );
+
+    let amount_sent_ld = ctx.accounts.oft_store.remove_dust(params.amount_ld);
+    require!(amount_sent_ld >= params.min_amount_ld, OftError::SlippageExceeded);
+
+    // lock into the escrow
+    transfer_token_from_user_to_vault(
+      ctx.accounts.signer.to_account_info(),
+      ctx.accounts.token_source.to_account_info(),
+      ctx.accounts.token_escrow.to_account_info(),
+      ctx.accounts.token_mint.to_account_info(),
+      ctx.accounts.token_program.to_account_info(),
+      amount_sent_ld,
+      ctx.accounts.token_mint.decimals,
+    )?;
+    ctx.accounts.oft_store.tvl_ld = ctx
+      .accounts
+      .oft_store
+      .tvl_ld
+      .checked_add(amount_sent_ld)
+      .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let amount_sd = ctx.accounts.oft_store.ld2sd(amount_sent_ld);
+    // echoed back by the remote side when tokens return to solana
+    let msg_type = if ctx.accounts.token_program.key() == TOKEN_2022_ID {
+      3 | msg_codec::TOKEN_2022_FLAG
+    } else {
+      3
+    };
+    let msg_receipt = oapp::endpoint_cpi::send(
+      ctx.accounts.oapp_config.endpoint_program,
+      ctx.accounts.oapp_config.key(),
+      ctx.remaining_accounts,
+      &[OAPP_SEED, &[ctx.accounts.oapp_config.bump]],
+      EndpointSendParams {
+        dst_eid: params.dst_eid,
+        receiver: ctx.accounts.peer.address,
+        message: msg_codec::encode(
+          msg_type,
+          ctx.accounts.token_mint.key().to_bytes(),
+          params.to,
+          0,
+          amount_sd as u128,
+          ctx.accounts.signer.key(),
+          &params.compose_msg,
+        ),
+        options: ctx
+          .accounts
+          .peer
+          .enforced_options
+          .combine_options(&params.compose_msg, &params.options)?,
+        native_fee: params.native_fee,
+        lz_token_fee: params.lz_token_fee,
+      },
+    )?;
+
+    emit!(OFTSent {
+      guid: msg_receipt.guid,
+      dst_eid: params.dst_eid,
+      from: ctx.accounts.signer.key(),
+      token_source: ctx.accounts.token_source.key(),
+      amount_sent_ld,
+      amount_received_ld: amount_sent_ld,
+    });
+    CrossChainLog::try_record(
+      &ctx.accounts.cross_chain_log.to_account_info(),
+      CrossChainLogEntry {
+        guid: msg_receipt.guid,
+        direction: MessageDirection::Outbound,
+        msg_type: 3,
+        mint: ctx.accounts.token_mint.key(),
+        recipient: params.to,
+        native_amount: 0,
+        token_amount: amount_sent_ld as u128,
+        outcome: MessageOutcome::Sent,
+        timestamp: Clock::get()?.unix_timestamp,
+      },
+    )?;
+    Ok(msg_receipt)
+  }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SendTokenParams {
+  pub dst_eid: u32,
+  pub to: [u8; 32],
+  pub amount_ld: u64,
+  pub min_amount_ld: u64,
+  pub options: Vec<u8>,
+  pub compose_msg: Option<Vec<u8>>,
+  pub native_fee: u64,
+  pub lz_token_fee: u64,
+}
@@ -5,14 +5,13 @@ use crate::*;
 pub struct SetPeer<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
+    /// CHECK: created, migrated or updated in apply
     #[account(
-        init_if_needed,
-        payer = admin,
-        space = 8 + Peer::INIT_SPACE,
+        mut,
         seeds = [PEER_SEED, &oapp_config.key().to_bytes(), &params.dst_eid.to_be_bytes()],
         bump
     )]
-    pub peer: Box<Account<'info, Peer>>,
+    pub peer: UncheckedAccount<'info>,
     #[account(
         seeds = [OAPP_SEED],
         bump = oapp_config.bump,
@@ -24,8 +23,65 @@ pub struct SetPeer<'info> {
 
 impl SetPeer<'_> {
     pub fn apply(ctx: &mut Context<SetPeer>, params: &SetPeerParams) -> Result<()> {
-        ctx.accounts.peer.address = params.peer;
-        ctx.accounts.peer.bump = ctx.bumps.peer;
+        let info = ctx.accounts.peer.to_account_info();
+        let space = 8 + Peer::INIT_SPACE;
+        let bump = ctx.bumps.peer;
+        let peer = if info.data_is_empty() {
+            // a new peer is enabled in both directions
+            let oapp_config = ctx.accounts.oapp_config.key();
+            let dst_eid = params.dst_eid.to_be_bytes();
+            let signer_seeds: &[&[&[u8]]] =
+                &[&[PEER_SEED, oapp_config.as_ref(), &dst_eid, &[bump]]];
+            create_pda_account(
+                &ctx.accounts.admin.to_account_info(),
+                &info,
+                &ctx.accounts.system_program.to_account_info(),
+                space,
+                signer_seeds,
+            )?;
+            Peer {
+                address: params.peer,
+                bump,
+                inbound_enabled: true,
+                outbound_enabled: true,
+                token_transfers_enabled: false,
+            }
+        } else {
+            require_keys_eq!(*info.owner, crate::ID, ErrorCode::ConstraintOwner);
+            require!(
+                info.try_borrow_data()?[..8] == Peer::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            if info.data_len() < space {
+                // set before the direction flags existed, those peers were open both ways
+                transfer_sol(
+                    ctx.accounts.admin.to_account_info(),
+                    info.clone(),
+                    Rent::get()?.minimum_balance(space).saturating_sub(info.lamports()),
+                )?;
+                info.realloc(space, true)?;
+                Peer {
+                    address: params.peer,
+                    bump,
+                    inbound_enabled: true,
+                    outbound_enabled: true,
+                    token_transfers_enabled: false,
+                }
+            } else {
+                // an existing peer keeps its flags
+                let mut peer = Peer::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+                peer.address = params.peer;
+                peer
+            }
+        };
+        peer.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        emit!(PeerSet {
+            eid: params.dst_eid,
+            peer: params.peer,
+            inbound_enabled: peer.inbound_enabled,
+            outbound_enabled: peer.outbound_enabled,
+            token_transfers_enabled: peer.token_transfers_enabled,
+        });
         Ok(())
     }
 }
@@ -0,0 +1,55 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(params: SetPeerEnabledParams)]
+pub struct SetPeerEnabled<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PEER_SEED, &oapp_config.key().to_bytes(), &params.dst_eid.to_be_bytes()],
+        bump = peer.bump
+    )]
+    pub peer: Box<Account<'info, Peer>>,
+    #[account(
+        seeds = [OAPP_SEED],
+        bump = oapp_config.bump,
+        has_one = admin @OftError::Unauthorized
+    )]
+    pub oapp_config: Box<Account<'info, OAppConfig>>,
+}
+
+impl SetPeerEnabled<'_> {
+    // enabled = true for enable_peer, false for disable_peer
+    // only the directions selected in params are changed
+    pub fn apply(
+        ctx: &mut Context<SetPeerEnabled>,
+        params: &SetPeerEnabledParams,
+        enabled: bool,
+    ) -> Result<()> {
+        if params.inbound {
+            ctx.accounts.peer.inbound_enabled = enabled;
+        }
+        if params.outbound {
+            ctx.accounts.peer.outbound_enabled = enabled;
+        }
+        if params.token_transfers {
+            ctx.accounts.peer.token_transfers_enabled = enabled;
+        }
+        emit!(PeerSet {
+            eid: params.dst_eid,
+            peer: ctx.accounts.peer.address,
+            inbound_enabled: ctx.accounts.peer.inbound_enabled,
+            outbound_enabled: ctx.accounts.peer.outbound_enabled,
+            token_transfers_enabled: ctx.accounts.peer.token_transfers_enabled,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetPeerEnabledParams {
+    pub dst_eid: u32,
+    pub inbound: bool,
+    pub outbound: bool,
+    pub token_transfers: bool,
+}
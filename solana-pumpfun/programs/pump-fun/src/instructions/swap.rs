@@ -0,0 +1,268 @@
+use crate::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+/// Sells `mint_in` into its curve and buys `mint_out` with the proceeds, charging the platform fee once.
+#[derive(Accounts)]
+pub struct Swap<'info> {
+  pub mint_in: Box<InterfaceAccount<'info, Mint>>,
+
+  #[account(
+    constraint = mint_out.key() != mint_in.key() @PumpFunError::SameMint,
+  )]
+  pub mint_out: Box<InterfaceAccount<'info, Mint>>,
+
+  #[account(
+    mut,
+    seeds = [
+      CONFIG_SEED,
+    ],
+    bump = global_config.bump
+  )]
+  pub global_config: Box<Account<'info, GlobalConfig>>,
+
+  #[account(
+    mut,
+    seeds = [
+      BONDING_CURVE_SEED,
+      mint_in.key().as_ref()
+    ],
+    bump = bonding_curve_in.bump,
+  )]
+  pub bonding_curve_in: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    mut,
+    seeds = [
+      BONDING_CURVE_SEED,
+      mint_out.key().as_ref()
+    ],
+    bump = bonding_curve_out.bump,
+  )]
+  pub bonding_curve_out: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    mut,
+    seeds = [
+      PLATFORM_FEE_VAULT_SEED,
+    ],
+    bump = platform_fee_vault.bump
+  )]
+  pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+  // optional, takes referral_fee_bps of the platform fee
+  #[account(
+    mut,
+    seeds = [
+      REFERRER_SEED,
+      referrer.authority.as_ref()
+    ],
+    bump = referrer.bump,
+    constraint = referrer.authority != user.key() @PumpFunError::SelfReferral,
+  )]
+  pub referrer: Option<Box<Account<'info, Referrer>>>,
+
+  #[account(
+    mut,
+    associated_token::mint = mint_in,
+    associated_token::authority = bonding_curve_in,
+    token::token_program = token_program_in,
+  )]
+  pub associted_bonding_curve_in: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    associated_token::mint = mint_in,
+    associated_token::authority = user,
+    token::token_program = token_program_in,
+  )]
+  pub associted_user_token_account_in: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(
+    mut,
+    associated_token::mint = mint_out,
+    associated_token::authority = bonding_curve_out,
+    token::token_program = token_program_out,
+  )]
+  pub associted_bonding_curve_out: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  /// CHECK: per-wallet launch window tally of mint_out, created in apply while the window is open
+  #[account(
+    mut,
+    seeds = [
+      LAUNCH_ALLOCATION_SEED,
+      mint_out.key().as_ref(),
+      user.key().as_ref()
+    ],
+    bump,
+  )]
+  pub launch_allocation: UncheckedAccount<'info>,
+
+  #[account(
+    init_if_needed,
+    associated_token::mint = mint_out,
+    associated_token::authority = user,
+    token::token_program = token_program_out,
+    payer = user,
+  )]
+  pub associted_user_token_account_out: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  #[account(mut)]
+    pub user: Signer<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program_in: Interface<'info, TokenInterface>,
+    pub token_program_out: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl Swap<'_> {
+  pub fn apply(ctx: &mut Context<Swap>, params: &SwapParams) -> Result<()> {
+    let decimals_in = ctx.accounts.mint_in.decimals;
+    let decimals_out = ctx.accounts.mint_out.decimals;
+    let clock = Clock::get()?;
+
+    // SOL of a completed curve is kept for migration
+    require!(!ctx.accounts.bonding_curve_in.complete, PumpFunError::AlreadyRaised);
+    ctx.accounts.bonding_curve_in.check_trading_open(&clock)?;
+    ctx.accounts.bonding_curve_out.check_trading_open(&clock)?;
+
+    // sell leg carries the platform fee, the buy leg only pays its creator
+    let sol_amount = sell_proceeds(ctx.accounts.associted_bonding_curve_in.amount, params.amount_in, decimals_in)?;
+    let fees_in = TradeFees::new(
+        sol_amount,
+        ctx.accounts.bonding_curve_in.creator_fee_bps,
+        ctx.accounts.global_config.fee_rate,
+        ctx.accounts.referrer.as_ref().map(|_| ctx.accounts.global_config.referral_fee_bps),
+    );
+    let budget = sol_amount.checked_sub(fees_in.total()).ok_or(PumpFunError::MathOverflow)?;
+    let fees_out = TradeFees::new(budget, ctx.accounts.bonding_curve_out.creator_fee_bps, 0, None);
+    let sol_in = budget.checked_sub(fees_out.total()).ok_or(PumpFunError::MathOverflow)?;
+
+    // checked against the funding goal and the available supply
+    let vault_amount_out = ctx.accounts.associted_bonding_curve_out.amount;
+    let token_output = buy_amount(vault_amount_out, sol_in, decimals_out)?;
+    require!(token_output >= params.min_amount_out, PumpFunError::SlippageExceed);
+    require!(
+        token_output <= available_supply(vault_amount_out)?,
+        PumpFunError::NotEnoughSuppply
+    );
+
+    ctx.accounts.bonding_curve_out.check_holding(
+        ctx.accounts.associted_user_token_account_out.amount
+            .checked_add(token_output)
+            .ok_or(PumpFunError::MathOverflow)?,
+    )?;
+    LaunchAllocation::check_buy(
+        &ctx.accounts.bonding_curve_out,
+        &ctx.accounts.launch_allocation.to_account_info(),
+        ctx.bumps.launch_allocation,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        token_output,
+    )?;
+
+    // sell leg
+    transfer_token_from_user_to_vault(
+        ctx.accounts.user.to_account_info(),
+        ctx.accounts.associted_user_token_account_in.to_account_info(),
+        ctx.accounts.associted_bonding_curve_in.to_account_info(),
+        ctx.accounts.mint_in.to_account_info(),
+        ctx.accounts.token_program_in.to_account_info(),
+        params.amount_in,
+        decimals_in,
+    )?;
+    ctx.accounts.bonding_curve_in.record_sell(params.amount_in, sol_amount)?;
+
+    // both curves may share a creator vault, creator fees stay on them unswept
+    ctx.accounts.bonding_curve_in.creator_fees_unswept = ctx.accounts.bonding_curve_in
+        .creator_fees_unswept
+        .checked_add(fees_in.creator_fee)
+        .ok_or(PumpFunError::MathOverflow)?;
+    pay_trade_fees(
+        &ctx.accounts.bonding_curve_in.to_account_info(),
+        &fees_in,
+        None,
+        &ctx.accounts.platform_fee_vault.to_account_info(),
+        ctx.accounts.referrer.as_deref_mut(),
+        &mut ctx.accounts.global_config,
+    )?;
+
+    // buy leg, paid straight from curve to curve
+    transfer_sol_from_vault_to_user(
+        ctx.accounts.bonding_curve_in.to_account_info(),
+        ctx.accounts.bonding_curve_out.to_account_info(),
+        budget,
+    )?;
+    ctx.accounts.bonding_curve_out.creator_fees_unswept = ctx.accounts.bonding_curve_out
+        .creator_fees_unswept
+        .checked_add(fees_out.creator_fee)
+        .ok_or(PumpFunError::MathOverflow)?;
+
+    let mint_out = ctx.accounts.mint_out.key();
+    let vault_seeds = &[
+        BONDING_CURVE_SEED,
+        mint_out.as_ref(),
+        &[ctx.accounts.bonding_curve_out.bump],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    transfer_token_from_vault_to_user(
+        ctx.accounts.bonding_curve_out.to_account_info(),
+        ctx.accounts.associted_bonding_curve_out.to_account_info(),
+        ctx.accounts.associted_user_token_account_out.to_account_info(),
+        ctx.accounts.mint_out.to_account_info(),
+        ctx.accounts.token_program_out.to_account_info(),
+        token_output,
+        decimals_out,
+        vault_signer_seeds,
+    )?;
+    ctx.accounts.bonding_curve_out.record_buy(token_output, sol_in)?;
+
+    emit!(SwapEvent {
+        user: ctx.accounts.user.key(),
+        mint_in: ctx.accounts.mint_in.key(),
+        mint_out,
+        token_input: params.amount_in,
+        sol_amount,
+        token_output,
+        creator_fee_in: fees_in.creator_fee,
+        creator_fee_out: fees_out.creator_fee,
+        platform_fee: fees_in.platform_fee,
+        referral_fee: fees_in.referral_fee,
+        referrer: ctx.accounts.referrer.as_ref().map(|referrer| referrer.authority),
+    });
+
+    if BondingCurve::completes(vault_amount_out - token_output) {
+        BondingCurve::complete(
+            &mut ctx.accounts.bonding_curve_out,
+            &mut ctx.accounts.global_config,
+            ctx.accounts.platform_fee_vault.to_account_info(),
+            None,
+        )?;
+    }
+
+    ctx.accounts.associted_bonding_curve_in.reload()?;
+    ctx.accounts.bonding_curve_in.assert_invariants(
+        &ctx.accounts.bonding_curve_in.to_account_info(),
+        ctx.accounts.associted_bonding_curve_in.amount,
+        decimals_in,
+    )?;
+    ctx.accounts.associted_bonding_curve_out.reload()?;
+    ctx.accounts.bonding_curve_out.assert_invariants(
+        &ctx.accounts.bonding_curve_out.to_account_info(),
+        ctx.accounts.associted_bonding_curve_out.amount,
+        decimals_out,
+    )
+  }
+
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SwapParams {
+  pub amount_in: u64,
+  // of mint_out, the only slippage bound of the swap
+  pub min_amount_out: u64,
+}
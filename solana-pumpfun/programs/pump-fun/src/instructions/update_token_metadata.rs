@@ -0,0 +1,148 @@
+use crate::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::{
+  metadata::{
+    mpl_token_metadata::{accounts::Metadata as MetadataAccount, types::DataV2},
+    update_metadata_accounts_v2, Metadata, UpdateMetadataAccountsV2,
+  },
+  token_2022,
+  token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use spl_token_metadata_interface::state::Field;
+
+#[derive(Accounts)]
+pub struct UpdateTokenMetadata<'info> {
+  #[account(mut)]
+  pub creator: Signer<'info>,
+
+  #[account(
+    mut,
+    mint::token_program = token_program,
+  )]
+  pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+  #[account(
+    seeds = [
+      BONDING_CURVE_SEED,
+      token_mint.key().as_ref()
+    ],
+    bump = bonding_curve.bump,
+    has_one = creator,
+    has_one = token_mint,
+  )]
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+  #[account(
+    associated_token::mint = token_mint,
+    associated_token::authority = bonding_curve,
+    token::token_program = token_program,
+  )]
+  pub associted_bonding_curve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+  /// CHECK: metaplex metadata, only written for legacy SPL mints
+  #[account(
+    mut,
+    address = MetadataAccount::find_pda(&token_mint.key()).0
+  )]
+  pub metadata: UncheckedAccount<'info>,
+
+  pub token_program: Interface<'info, TokenInterface>,
+  pub token_metadata_program: Program<'info, Metadata>,
+  pub system_program: Program<'info, System>,
+}
+
+impl UpdateTokenMetadata<'_> {
+  pub fn apply(ctx: &mut Context<UpdateTokenMetadata>, params: &UpdateTokenMetadataParams) -> Result<()> {
+    require!(
+      ctx.accounts.bonding_curve.metadata_authority == MetadataAuthority::Creator,
+      PumpFunError::MetadataNotUpdatable
+    );
+    // locked for good once the curve completes
+    require!(
+      ctx.accounts.associted_bonding_curve.amount > INIT_SUPPLY,
+      PumpFunError::MetadataLocked
+    );
+    validate_token_name(&params.name)?;
+    validate_token_symbol(&params.symbol)?;
+    validate_token_uri(&params.uri)?;
+
+    let token_mint = ctx.accounts.token_mint.key();
+    let seeds = &[BONDING_CURVE_SEED, token_mint.as_ref(), &[ctx.accounts.bonding_curve.bump]];
+    let signer_seeds = [&seeds[..]];
+
+    if ctx.accounts.token_program.key() == token_2022::ID {
+      for (field, value) in [
+        (Field::Name, &params.name),
+        (Field::Symbol, &params.symbol),
+        (Field::Uri, &params.uri),
+      ] {
+        let ix = spl_token_metadata_interface::instruction::update_field(
+          &token_2022::ID,
+          &token_mint,
+          &ctx.accounts.bonding_curve.key(),
+          field,
+          value.clone(),
+        );
+        invoke_signed(
+          &ix,
+          &[
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.bonding_curve.to_account_info(),
+          ],
+          &signer_seeds,
+        )?;
+      }
+
+      // the mint was reallocated to fit the new metadata, creator covers the extra rent
+      let mint_info = ctx.accounts.token_mint.to_account_info();
+      let required_lamports = Rent::get()?.minimum_balance(mint_info.data_len());
+      if mint_info.lamports() < required_lamports {
+        transfer_sol(
+          ctx.accounts.creator.to_account_info(),
+          mint_info.clone(),
+          required_lamports - mint_info.lamports(),
+        )?;
+      }
+    } else {
+      update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+          ctx.accounts.token_metadata_program.to_account_info(),
+          UpdateMetadataAccountsV2 {
+            metadata: ctx.accounts.metadata.to_account_info(),
+            update_authority: ctx.accounts.bonding_curve.to_account_info(),
+          },
+          &signer_seeds,
+        ),
+        None,
+        Some(DataV2 {
+          name: params.name.clone(),
+          symbol: params.symbol.clone(),
+          uri: params.uri.clone(),
+          seller_fee_basis_points: 0,
+          creators: None,
+          collection: None,
+          uses: None,
+        }),
+        None,
+        None,
+      )?;
+    }
+
+    emit!(UpdateTokenMetadataEvent {
+      mint: token_mint,
+      creator: ctx.accounts.creator.key(),
+      token_name: params.name.clone(),
+      token_symbol: params.symbol.clone(),
+      token_uri: params.uri.clone(),
+    });
+    Ok(())
+  }
+}
+
+// socials and other extras belong in the json behind the uri
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateTokenMetadataParams {
+  pub name: String,
+  pub symbol: String,
+  pub uri: String,
+}
@@ -0,0 +1,43 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct UpdateTradingStart<'info> {
+  pub creator: Signer<'info>,
+
+  #[account(
+    mut,
+    seeds = [
+      BONDING_CURVE_SEED,
+      bonding_curve.token_mint.as_ref()
+    ],
+    bump = bonding_curve.bump,
+    has_one = creator,
+  )]
+  pub bonding_curve: Box<Account<'info, BondingCurve>>,
+}
+
+impl UpdateTradingStart<'_> {
+  pub fn apply(ctx: &mut Context<UpdateTradingStart>, params: &UpdateTradingStartParams) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let curve = &mut ctx.accounts.bonding_curve;
+    require!(now < curve.trading_start_ts, PumpFunError::TradingAlreadyStarted);
+    // buyers plan around the announced time, it can only move closer
+    require!(params.trading_start_ts <= curve.trading_start_ts, PumpFunError::TradingStartPostponed);
+
+    let previous_start_ts = curve.trading_start_ts;
+    curve.trading_start_ts = params.trading_start_ts.max(now);
+
+    emit!(UpdateTradingStartEvent {
+      mint: curve.token_mint,
+      creator: curve.creator,
+      previous_start_ts,
+      trading_start_ts: curve.trading_start_ts,
+    });
+    Ok(())
+  }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateTradingStartParams {
+  pub trading_start_ts: i64,
+}
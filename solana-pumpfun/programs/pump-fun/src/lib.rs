@@ -6,6 +6,7 @@ pub mod utils;
 mod events;
 mod errors;
 pub mod msg_codec;
+pub mod compose_msg_codec;
 
 use instructions::*;
 use state::*;
@@ -18,13 +19,13 @@ use oapp::{
 
 declare_id!("CiQZ4gYrjqkWE6QLWcRt5zYkjKrond7Ww1SD33qAtGQr");
 
-// pub const OFT_SEED: &[u8] = b"Oft";
+pub const OFT_SEED: &[u8] = b"Oft";
 pub const OAPP_SEED: &[u8] = b"OApp";
 pub const PEER_SEED: &[u8] = b"Peer";
 pub const ENFORCED_OPTIONS_SEED: &[u8] = b"EnforcedOptions";
 pub const LZ_RECEIVE_TYPES_SEED: &[u8] = oapp::LZ_RECEIVE_TYPES_SEED;
 
-// pub const SHARED_DECIMALS:u8 = 6;
+pub const SHARED_DECIMALS:u8 = 6;
 pub const MAX_SUPPLY:u64 = 1000_000_000_000_000;
 pub const INIT_SUPPLY:u64 = 200_000_000_000_000;
 
@@ -40,6 +41,14 @@ pub mod pump_fun {
     UpdateGlobalConfig::apply(&mut ctx, &params)
   }
 
+  pub fn migrate_global_config(mut ctx: Context<MigrateGlobalConfig>, params: MigrateGlobalConfigParams) -> Result<()> {
+    MigrateGlobalConfig::apply(&mut ctx, &params)
+  }
+
+  pub fn init_platform_fee_vault(mut ctx: Context<InitPlatformFeeVault>) -> Result<()> {
+    InitPlatformFeeVault::apply(&mut ctx)
+  }
+
   pub fn init_oapp(mut ctx: Context<InitOApp>, params: InitOAppParams) -> Result<()> {
     InitOApp::apply(&mut ctx, &params)
   }
@@ -52,21 +61,91 @@ pub mod pump_fun {
     CreateToken::apply(&mut ctx, &params)
   }
 
-  pub fn buy(mut ctx: Context<Buy>, amount: u64, max_sol_cost: u64) -> Result<()> {
-    Buy::apply(&mut ctx, amount, max_sol_cost)
+  pub fn update_token_metadata(
+      mut ctx: Context<UpdateTokenMetadata>,
+      params: UpdateTokenMetadataParams,
+  ) -> Result<()> {
+    UpdateTokenMetadata::apply(&mut ctx, &params)
+  }
+
+  pub fn update_trading_start(
+      mut ctx: Context<UpdateTradingStart>,
+      params: UpdateTradingStartParams,
+  ) -> Result<()> {
+    UpdateTradingStart::apply(&mut ctx, &params)
   }
 
-  pub fn buy_in_sol(mut ctx: Context<BuyInSol>, amount_min: u64, sol: u64) -> Result<()> {
-    BuyInSol::apply(&mut ctx, amount_min, sol)
+  pub fn buy(mut ctx: Context<Buy>, amount: u64, max_sol_cost: u64, allow_partial: bool) -> Result<()> {
+    Buy::apply(&mut ctx, amount, max_sol_cost, allow_partial)
+  }
+
+  pub fn buy_in_sol(mut ctx: Context<BuyInSol>, amount_min: u64, sol: u64, allow_partial: bool) -> Result<()> {
+    BuyInSol::apply(&mut ctx, amount_min, sol, allow_partial)
+  }
+
+  pub fn buy_many<'info>(mut ctx: Context<'_, '_, 'info, 'info, BuyMany<'info>>, params: BuyManyParams) -> Result<()> {
+    BuyMany::apply(&mut ctx, &params)
+  }
+
+  pub fn buy_presale(mut ctx: Context<BuyPresale>, params: BuyPresaleParams) -> Result<()> {
+    BuyPresale::apply(&mut ctx, &params)
   }
 
   pub fn sell(mut ctx: Context<Sell>, amount: u64) -> Result<()> {
     Sell::apply(&mut ctx, amount)
   }
 
+  pub fn swap(mut ctx: Context<Swap>, params: SwapParams) -> Result<()> {
+    Swap::apply(&mut ctx, &params)
+  }
+
+  pub fn claim_creator_fees(mut ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    ClaimCreatorFees::apply(&mut ctx)
+  }
+
+  pub fn register_referrer(mut ctx: Context<RegisterReferrer>) -> Result<()> {
+    RegisterReferrer::apply(&mut ctx)
+  }
+
+  pub fn claim_referral_fees(mut ctx: Context<ClaimReferralFees>) -> Result<()> {
+    ClaimReferralFees::apply(&mut ctx)
+  }
+
+  pub fn place_limit_order(mut ctx: Context<PlaceLimitOrder>, params: PlaceLimitOrderParams) -> Result<()> {
+    PlaceLimitOrder::apply(&mut ctx, &params)
+  }
+
+  pub fn cancel_limit_order(mut ctx: Context<CancelLimitOrder>) -> Result<()> {
+    CancelLimitOrder::apply(&mut ctx)
+  }
+
+  pub fn execute_orders<'info>(mut ctx: Context<'_, '_, 'info, 'info, ExecuteOrders<'info>>) -> Result<()> {
+    ExecuteOrders::apply(&mut ctx)
+  }
+
+  pub fn create_dca(mut ctx: Context<CreateDca>, params: CreateDcaParams) -> Result<()> {
+    CreateDca::apply(&mut ctx, &params)
+  }
+
+  pub fn execute_dca(mut ctx: Context<ExecuteDca>) -> Result<()> {
+    ExecuteDca::apply(&mut ctx)
+  }
+
+  pub fn cancel_dca(mut ctx: Context<CancelDca>) -> Result<()> {
+    CancelDca::apply(&mut ctx)
+  }
+
+  pub fn claim_platform_fees(mut ctx: Context<ClaimPlatformFees>, params: ClaimPlatformFeesParams) -> Result<()> {
+    ClaimPlatformFees::apply(&mut ctx, &params)
+  }
+
   pub fn withdraw(mut ctx: Context<Withdraw>) -> Result<()> {
     Withdraw::apply(&mut ctx)
   }
+
+  pub fn migrate_bonding_curve(mut ctx: Context<MigrateBondingCurve>, params: MigrateBondingCurveParams) -> Result<()> {
+    MigrateBondingCurve::apply(&mut ctx, &params)
+  }
   // OFT
   // ============================== Admin ==============================
   pub fn transfer_oapp_admin(
@@ -76,11 +155,48 @@ pub mod pump_fun {
     TransferOAppAdmin::apply(&mut ctx, &params)
   }
 
+  pub fn init_oft(mut ctx: Context<InitOft>) -> Result<()> {
+    InitOft::apply(&mut ctx)
+  }
+
   pub fn set_peer(mut ctx: Context<SetPeer>, params: SetPeerParams) -> Result<()> {
       SetPeer::apply(&mut ctx, &params)
   }
 
+  pub fn enable_peer(mut ctx: Context<SetPeerEnabled>, params: SetPeerEnabledParams) -> Result<()> {
+      SetPeerEnabled::apply(&mut ctx, &params, true)
+  }
+
+  pub fn disable_peer(mut ctx: Context<SetPeerEnabled>, params: SetPeerEnabledParams) -> Result<()> {
+      SetPeerEnabled::apply(&mut ctx, &params, false)
+  }
+
+  pub fn close_peer(mut ctx: Context<ClosePeer>, params: ClosePeerParams) -> Result<()> {
+      ClosePeer::apply(&mut ctx, &params)
+  }
+
+  pub fn reconcile_peer_sol_vault(
+    mut ctx: Context<ReconcilePeerSolVault>,
+    params: ReconcilePeerSolVaultParams,
+  ) -> Result<()> {
+    ReconcilePeerSolVault::apply(&mut ctx, &params)
+  }
+
+  pub fn init_cross_chain_log(
+    mut ctx: Context<InitCrossChainLog>,
+    params: InitCrossChainLogParams,
+  ) -> Result<()> {
+    InitCrossChainLog::apply(&mut ctx, &params)
+  }
+
   // ============================== Public ==============================
+  pub fn fund_peer_sol_vault(
+    mut ctx: Context<FundPeerSolVault>,
+    params: FundPeerSolVaultParams,
+  ) -> Result<()> {
+    FundPeerSolVault::apply(&mut ctx, &params)
+  }
+
   pub fn lz_receive(mut ctx: Context<LzReceive>, params: LzReceiveParams) -> Result<()> {
       LzReceive::apply(&mut ctx, &params)
   }
@@ -92,6 +208,13 @@ pub mod pump_fun {
       LzReceiveTypes::apply(&ctx, &params)
   }
 
+  pub fn get_cross_chain_log(
+    ctx: Context<GetCrossChainLog>,
+    params: GetCrossChainLogParams,
+  ) -> Result<Vec<CrossChainLogEntry>> {
+    GetCrossChainLog::apply(&ctx, &params)
+  }
+
   pub fn quote(ctx: Context<Quote>, params: QuoteParams) -> Result<MessagingFee> {
     Quote::apply(&ctx, &params)
   }
@@ -103,4 +226,11 @@ pub mod pump_fun {
     Send::apply(&mut ctx, &params)
   }
 
+  pub fn send_token(
+    mut ctx: Context<SendToken>,
+    params: SendTokenParams,
+  ) -> Result<MessagingReceipt> {
+    SendToken::apply(&mut ctx, &params)
+  }
+
 }
@@ -5,10 +5,16 @@ const MEME_TOKEN_ADDRESS_OFFSET: usize = 1;
 const RECEIPT_ADDRESS_OFFSET: usize = 33;
 const SOL_AMOUNT_OFFSET: usize = 65;
 const TOKEN_AMOUNT_OFFSET: usize = 81;
+const COMPOSE_FROM_OFFSET: usize = 113;
+
+// set in the msg type for Token-2022 mints,
+// lz_receive_types can not read the mint to find its token program,
+// the evm TokenFactory sets it for mints registered with setSolanaTokenProgram
+pub const TOKEN_2022_FLAG: u8 = 0x80;
 
 // make msg to cross chain
 pub fn encode(
-  msg_type: u8, // buy: 1, sell: 2
+  msg_type: u8, // buy: 1, sell: 2, send token: 3
   meme_token_addr: [u8; 32],
   to_addr: [u8; 32],
   eth_amount: u128, //16 byte
@@ -41,8 +47,20 @@ pub fn encode(
   }
 }
 
+pub fn msg_type(message:&[u8]) -> u8 {
+    message[0] & !TOKEN_2022_FLAG
+}
+
+pub fn is_token_2022(message:&[u8]) -> bool {
+    message[0] & TOKEN_2022_FLAG != 0
+}
+
 pub fn is_buy_token(message:&[u8]) -> bool {
-    message[0] == 1
+    msg_type(message) == 1
+}
+
+pub fn is_send_token(message:&[u8]) -> bool {
+    msg_type(message) == 3
 }
 
 pub fn get_meme_addr(message: &[u8]) -> [u8; 32] {
@@ -63,8 +81,30 @@ pub fn get_sol_amount(message: &[u8]) -> u64 {
     u64::from_be_bytes(sol_amount)
 }
 
-// pub fn get_token_amount(message: &[u8]) -> u64 {
-//     let mut token_amount = [0; 8];
-//     token_amount.copy_from_slice(&message[TOKEN_AMOUNT_OFFSET..]);
-//     u64::from_be_bytes(token_amount)
-// }
\ No newline at end of file
+// amount forwarded with the compose msg, read from the msg alone so lz_receive_types derives
+// the same compose accounts as lz_receive: lamports spent for buys, tokens in local decimals
+// for send token msgs (meme mints have TOKEN_DECIMALS)
+pub fn compose_amount(message: &[u8]) -> u64 {
+    if is_buy_token(message) {
+        get_sol_amount(message)
+    } else {
+        get_token_amount(message)
+            .saturating_mul(10u64.pow((TOKEN_DECIMALS - SHARED_DECIMALS) as u32))
+    }
+}
+
+// [composeFrom][composeMsg] appended after the fixed header, if any
+pub fn compose_msg(message: &[u8]) -> Option<Vec<u8>> {
+    if message.len() > COMPOSE_FROM_OFFSET {
+        Some(message[COMPOSE_FROM_OFFSET..].to_vec())
+    } else {
+        None
+    }
+}
+
+// token amount in shared decimals for send token msgs
+pub fn get_token_amount(message: &[u8]) -> u64 {
+    let mut token_amount = [0; 8];
+    token_amount.copy_from_slice(&message[TOKEN_AMOUNT_OFFSET+24..COMPOSE_FROM_OFFSET]);
+    u64::from_be_bytes(token_amount)
+}
\ No newline at end of file
@@ -0,0 +1,159 @@
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum MetadataAuthority {
+    Immutable,
+    // bonding_curve keeps the update authority, the creator updates through
+    // update_token_metadata until the curve completes
+    Creator,
+    // update authority handed to this address at creation
+    Address(Pubkey),
+}
+
+/// Per-token curve state, stored on the bonding_curve PDA that also holds the raised SOL.
+#[account]
+#[derive(InitSpace)]
+pub struct BondingCurve {
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    pub token_program: Pubkey,
+    pub bump: u8,
+    // supply guarantees, fixed at creation
+    pub mint_authority_revoked: bool,
+    pub freeze_authority_revoked: bool,
+    pub metadata_authority: MetadataAuthority,
+    // fixed at creation from the config default or the creator's override
+    pub creator_fee_bps: u16,
+    // creator fees of inbound cross-chain buys, held here until swept into the creator vault
+    pub creator_fees_unswept: u64,
+    // set once the last curve token is sold
+    pub complete: bool,
+    // curve accounting, checked against the vaults after every trade
+    pub tokens_sold: u64,
+    pub sol_reserve: u64, // lamports backing the sold tokens, fees excluded
+    // no trades before this time, except the creator's initial buy
+    pub trading_start_ts: i64,
+    // anti-sniper window, counted from the slot trading opened in, 0 until then
+    pub launch_slot: u64,
+    pub launch_protection: Option<LaunchProtection>,
+    // allowlist of (wallet, token allocation) leaves, buy_presale is open until trading_start_ts
+    pub presale_merkle_root: Option<[u8; 32]>,
+    // max share of MAX_SUPPLY one wallet may hold after a buy, lifted once the curve completes
+    pub max_holding_bps: Option<u16>,
+    // curve tokens moved out by withdraw once the curve completed, never sold
+    pub tokens_withdrawn: u64,
+}
+
+impl BondingCurve {
+    // rejects trades before the start time, the first trade after it opens the launch window
+    pub fn check_trading_open(&mut self, clock: &Clock) -> Result<()> {
+        require!(clock.unix_timestamp >= self.trading_start_ts, PumpFunError::TradingNotStarted);
+        if self.launch_slot == 0 {
+            self.launch_slot = clock.slot;
+        }
+        Ok(())
+    }
+
+    // launch protection while the window is open
+    pub fn launch_window(&self, slot: u64) -> Option<LaunchProtection> {
+        self.launch_protection
+            .filter(|protection| slot < self.launch_slot.saturating_add(protection.slots))
+    }
+
+    pub fn record_buy(&mut self, tokens: u64, sol: u64) -> Result<()> {
+        self.tokens_sold = self.tokens_sold.checked_add(tokens).ok_or(PumpFunError::MathOverflow)?;
+        self.sol_reserve = self.sol_reserve.checked_add(sol).ok_or(PumpFunError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_sell(&mut self, tokens: u64, sol: u64) -> Result<()> {
+        self.tokens_sold = self.tokens_sold.checked_sub(tokens).ok_or(PumpFunError::CurveInvariantViolated)?;
+        self.sol_reserve = self.sol_reserve.checked_sub(sol).ok_or(PumpFunError::CurveInvariantViolated)?;
+        Ok(())
+    }
+
+    // post-trade checks: the vault holds what was neither sold nor withdrawn, the curve holds rent + reserve + unswept fees,
+    // and until completion the reserve covers buying back every sold token
+    pub fn assert_invariants(&self, info: &AccountInfo, vault_amount: u64, decimals: u8) -> Result<()> {
+        require!(
+            vault_amount
+                .checked_add(self.tokens_sold)
+                .and_then(|amount| amount.checked_add(self.tokens_withdrawn))
+                == Some(MAX_SUPPLY),
+            PumpFunError::CurveInvariantViolated
+        );
+        let required_lamports = Rent::get()?
+            .minimum_balance(info.data_len())
+            .checked_add(self.sol_reserve)
+            .and_then(|lamports| lamports.checked_add(self.creator_fees_unswept))
+            .ok_or(PumpFunError::MathOverflow)?;
+        require!(info.lamports() >= required_lamports, PumpFunError::CurveInvariantViolated);
+        require!(
+            self.complete || self.sol_reserve >= implied_reserve(self.tokens_sold, decimals),
+            PumpFunError::CurveInvariantViolated
+        );
+        Ok(())
+    }
+
+    pub fn check_holding(&self, balance_after: u64) -> Result<()> {
+        if let (Some(bps), false) = (self.max_holding_bps, self.complete) {
+            require!(
+                balance_after <= calculate_fee(MAX_SUPPLY, bps),
+                PumpFunError::MaxHoldingExceeded
+            );
+        }
+        Ok(())
+    }
+
+    // the curve completes when the vault is down to the tokens kept for migration
+    pub fn completes(vault_amount: u64) -> bool {
+        vault_amount <= INIT_SUPPLY
+    }
+
+    // graduation fee to the platform and completion bonus to the creator, out of the raised SOL.
+    // without the creator vault (inbound cross-chain buys) the bonus waits on the curve like creator fees
+    pub fn complete<'info>(
+        curve: &mut Account<'info, BondingCurve>,
+        config: &mut Account<'info, GlobalConfig>,
+        platform_fee_vault: AccountInfo<'info>,
+        creator_vault: Option<&mut Account<'info, CreatorVault>>,
+    ) -> Result<()> {
+        let info = curve.to_account_info();
+        let sol_raised = curve.sol_reserve;
+        let graduation_fee = config.graduation_fee.min(sol_raised);
+        let creator_bonus = config.creator_completion_bonus.min(sol_raised - graduation_fee);
+
+        transfer_sol_from_vault_to_user(info.clone(), platform_fee_vault, graduation_fee)?;
+        config.total_platform_fees = config
+            .total_platform_fees
+            .checked_add(graduation_fee)
+            .ok_or(PumpFunError::MathOverflow)?;
+        match creator_vault {
+            Some(vault) => {
+                transfer_sol_from_vault_to_user(info, vault.to_account_info(), creator_bonus)?;
+                vault.total_accrued = vault
+                    .total_accrued
+                    .checked_add(creator_bonus)
+                    .ok_or(PumpFunError::MathOverflow)?;
+            }
+            None => {
+                curve.creator_fees_unswept = curve
+                    .creator_fees_unswept
+                    .checked_add(creator_bonus)
+                    .ok_or(PumpFunError::MathOverflow)?;
+            }
+        }
+        curve.sol_reserve = sol_raised - graduation_fee - creator_bonus;
+        curve.complete = true;
+
+        emit!(CurveCompleted {
+            mint: curve.token_mint,
+            creator: curve.creator,
+            sol_raised,
+            graduation_fee,
+            creator_bonus,
+            sol_for_liquidity: sol_raised - graduation_fee - creator_bonus,
+        });
+        Ok(())
+    }
+}
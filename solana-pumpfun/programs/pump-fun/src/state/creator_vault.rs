@@ -0,0 +1,36 @@
+use crate::*;
+
+pub const CREATOR_VAULT_SEED: &[u8] = b"pumpfun_creator_vault";
+
+/// Creator fees of all tokens launched by one creator, claimed with claim_creator_fees.
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorVault {
+    pub creator: Pubkey,
+    pub bump: u8,
+    pub total_accrued: u64,
+    pub total_claimed: u64,
+}
+
+impl CreatorVault {
+    pub fn claimable(&self) -> u64 {
+        self.total_accrued - self.total_claimed
+    }
+
+    // inbound cross-chain buys can not reach the vault, their fees wait on the curve until swept here
+    pub fn sweep<'info>(
+        vault: &mut Account<'info, CreatorVault>,
+        curve: &mut Account<'info, BondingCurve>,
+    ) -> Result<u64> {
+        let amount = curve.creator_fees_unswept;
+        if amount > 0 {
+            transfer_sol_from_vault_to_user(curve.to_account_info(), vault.to_account_info(), amount)?;
+            curve.creator_fees_unswept = 0;
+            vault.total_accrued = vault
+                .total_accrued
+                .checked_add(amount)
+                .ok_or(PumpFunError::MathOverflow)?;
+        }
+        Ok(amount)
+    }
+}
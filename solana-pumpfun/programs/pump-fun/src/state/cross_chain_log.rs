@@ -0,0 +1,125 @@
+use crate::*;
+
+pub const CROSS_CHAIN_LOG_SEED: &[u8] = b"CrossChainLog";
+pub const CROSS_CHAIN_LOG_CAPACITY: usize = 32;
+// return data is capped at 1024 bytes
+pub const CROSS_CHAIN_LOG_VIEW_MAX: usize = 7;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum MessageDirection {
+    Inbound,
+    Outbound,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum MessageOutcome {
+    Executed, // inbound msg applied
+    Ignored,  // inbound msg with a type this program does not handle
+    Sent,     // outbound msg handed to the endpoint
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct CrossChainLogEntry {
+    pub guid: [u8; 32],
+    pub direction: MessageDirection,
+    pub msg_type: u8,
+    pub mint: Pubkey,
+    pub recipient: [u8; 32],
+    pub native_amount: u128, // lamports inbound, remote native amount outbound
+    pub token_amount: u128,
+    pub outcome: MessageOutcome,
+    pub timestamp: i64,
+}
+
+/// Ring buffer of the last CROSS_CHAIN_LOG_CAPACITY messages exchanged with one peer.
+#[account]
+#[derive(InitSpace)]
+pub struct CrossChainLog {
+    pub eid: u32,
+    pub bump: u8,
+    pub head: u16, // next slot to write
+    pub total: u64,
+    #[max_len(CROSS_CHAIN_LOG_CAPACITY)]
+    pub entries: Vec<CrossChainLogEntry>,
+}
+
+impl CrossChainLog {
+    pub fn record(&mut self, entry: CrossChainLogEntry) {
+        if self.entries.len() < CROSS_CHAIN_LOG_CAPACITY {
+            self.entries.push(entry);
+        } else {
+            self.entries[self.head as usize] = entry;
+        }
+        self.head = ((self.head as usize + 1) % CROSS_CHAIN_LOG_CAPACITY) as u16;
+        self.total += 1;
+    }
+
+    // newest first
+    pub fn latest(&self, offset: usize, limit: usize) -> Vec<CrossChainLogEntry> {
+        let len = self.entries.len();
+        (offset..len.min(offset.saturating_add(limit)))
+            .map(|i| self.entries[(self.head as usize + len - 1 - i) % len].clone())
+            .collect()
+    }
+
+    // the log is optional, executors and clients always pass its address
+    // and entries are only written once the admin has created it
+    pub fn try_record(info: &AccountInfo, entry: CrossChainLogEntry) -> Result<()> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(());
+        }
+        let mut log = CrossChainLog::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        log.record(entry);
+        log.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::cross_chain_log::*;
+
+    fn entry(n: u8) -> CrossChainLogEntry {
+        CrossChainLogEntry {
+            guid: [n; 32],
+            direction: MessageDirection::Inbound,
+            msg_type: 1,
+            mint: Pubkey::default(),
+            recipient: [0; 32],
+            native_amount: n as u128,
+            token_amount: 0,
+            outcome: MessageOutcome::Executed,
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_record_wraps_around() {
+        let mut log = CrossChainLog { eid: 1, bump: 0, head: 0, total: 0, entries: vec![] };
+        for n in 0..(CROSS_CHAIN_LOG_CAPACITY + 3) as u8 {
+            log.record(entry(n));
+        }
+        assert_eq!(log.entries.len(), CROSS_CHAIN_LOG_CAPACITY);
+        assert_eq!(log.total, CROSS_CHAIN_LOG_CAPACITY as u64 + 3);
+        assert_eq!(log.head, 3);
+
+        let latest = log.latest(0, 2);
+        assert_eq!(latest[0].native_amount, CROSS_CHAIN_LOG_CAPACITY as u128 + 2);
+        assert_eq!(latest[1].native_amount, CROSS_CHAIN_LOG_CAPACITY as u128 + 1);
+        // oldest kept entry
+        let oldest = log.latest(CROSS_CHAIN_LOG_CAPACITY - 1, 1);
+        assert_eq!(oldest[0].native_amount, 3);
+    }
+
+    #[test]
+    fn test_latest_before_full() {
+        let mut log = CrossChainLog { eid: 1, bump: 0, head: 0, total: 0, entries: vec![] };
+        for n in 0..3 {
+            log.record(entry(n));
+        }
+        let latest = log.latest(0, CROSS_CHAIN_LOG_VIEW_MAX);
+        assert_eq!(latest.len(), 3);
+        assert_eq!(latest[0].native_amount, 2);
+        assert_eq!(latest[2].native_amount, 0);
+        assert!(log.latest(5, 1).is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::*;
+
+pub const DCA_VAULT_SEED: &[u8] = b"pumpfun_dca_vault";
+
+/// SOL of one owner spent on one mint in fixed slices, the SOL sits on the account next to its rent.
+#[account]
+#[derive(InitSpace)]
+pub struct DcaVault {
+    pub owner: Pubkey,
+    pub token_mint: Pubkey,
+    pub bump: u8,
+    pub amount_per_interval: u64, // lamports, fees included
+    pub interval_seconds: i64,
+    pub max_price: u64, // lamports per whole token, fees included
+    pub last_executed_ts: i64,
+    pub total_spent: u64,
+    pub total_tokens: u64,
+}
+
+impl DcaVault {
+    pub fn new(owner: Pubkey, token_mint: Pubkey, bump: u8, params: &CreateDcaParams) -> Self {
+        DcaVault {
+            owner,
+            token_mint,
+            bump,
+            amount_per_interval: params.amount_per_interval,
+            interval_seconds: params.interval_seconds,
+            max_price: params.max_price,
+            last_executed_ts: 0,
+            total_spent: 0,
+            total_tokens: 0,
+        }
+    }
+
+    pub fn validate(&self, min_slice: u64) -> Result<()> {
+        require!(
+            self.amount_per_interval >= min_slice && self.interval_seconds > 0 && self.max_price > 0,
+            PumpFunError::InvalidDcaParams
+        );
+        Ok(())
+    }
+
+    // smallest slice that still buys after fees, buy_amount needs at least 2 lamports
+    pub fn min_slice(creator_fee_bps: u16, fee_rate: u32) -> Result<u64> {
+        gross_up(2, creator_fee_bps, fee_rate).ok_or(error!(PumpFunError::InvalidDcaParams))
+    }
+
+    // a slice that would leave less than min_slice behind takes the rest of the balance with it
+    pub fn next_slice(&self, balance: u64, min_slice: u64) -> u64 {
+        if balance.saturating_sub(self.amount_per_interval) < min_slice {
+            balance
+        } else {
+            self.amount_per_interval
+        }
+    }
+
+    // SOL of the next slice out of `balance` and its fees,
+    // the platform fee may have been raised since the vault was created
+    pub fn slice(&self, balance: u64, creator_fee_bps: u16, fee_rate: u32) -> Result<(u64, TradeFees)> {
+        let min_slice = Self::min_slice(creator_fee_bps, fee_rate)?;
+        require!(balance >= min_slice, PumpFunError::DcaEmpty);
+        let sol = self.next_slice(balance, min_slice);
+        require!(sol >= min_slice, PumpFunError::DcaSliceTooSmall);
+        Ok((sol, TradeFees::new(sol, creator_fee_bps, fee_rate, None)))
+    }
+
+    // the first slice is due right away
+    pub fn is_due(&self, now: i64) -> bool {
+        self.last_executed_ts == 0 || now >= self.last_executed_ts.saturating_add(self.interval_seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault(amount_per_interval: u64) -> DcaVault {
+        let params = CreateDcaParams { deposit: 0, amount_per_interval, interval_seconds: 60, max_price: 1 };
+        DcaVault::new(Pubkey::default(), Pubkey::default(), 0, &params)
+    }
+
+    #[test]
+    fn test_is_due() {
+        let mut vault = vault(1);
+        assert!(vault.is_due(1_000));
+        vault.last_executed_ts = 1_000;
+        assert!(!vault.is_due(1_059));
+        assert!(vault.is_due(1_060));
+    }
+
+    #[test]
+    fn test_next_slice() {
+        let vault = vault(100);
+        assert_eq!(vault.next_slice(1_000, 3), 100);
+        assert_eq!(vault.next_slice(103, 3), 100);
+        // the dust goes with the last slice
+        assert_eq!(vault.next_slice(102, 3), 102);
+        assert_eq!(vault.next_slice(50, 3), 50);
+    }
+
+    #[test]
+    fn test_slice() {
+        let vault = vault(1_000_000);
+        let min_slice = DcaVault::min_slice(100, 1_000).unwrap();
+        assert!(vault.validate(min_slice).is_ok());
+
+        // one lamport of dust is folded into the last slice
+        let mut balance = 3_000_001;
+        let mut slices = Vec::new();
+        while let Ok((sol, fees)) = vault.slice(balance, 100, 1_000) {
+            assert_eq!(fees, TradeFees::new(sol, 100, 1_000, None));
+            assert!(sol - fees.total() > 1);
+            balance -= sol;
+            slices.push(sol);
+        }
+        assert_eq!(slices, [1_000_000, 1_000_000, 1_000_001]);
+        assert!(vault.slice(min_slice - 1, 100, 1_000).is_err());
+
+        // slices set before a fee raise no longer buy anything
+        let vault = vault(2);
+        assert!(vault.slice(100, 5_000, 0).is_err());
+        assert!(vault.validate(DcaVault::min_slice(5_000, 0).unwrap()).is_err());
+    }
+}
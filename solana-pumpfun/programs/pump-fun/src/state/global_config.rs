@@ -4,14 +4,56 @@ pub const CONFIG_SEED: &[u8] = b"pumpfun_config";
 pub const TOKEN_SEED: &[u8] = b"pumpfun_token";
 // pub const TOKEN_MINT_AUTHORITY_SEED: &str = "pumpfun_mint_authority";
 pub const BONDING_CURVE_SEED: &[u8] = b"pumpfun_bonding_curve";
-pub const WITHDRAWABLE_MIN_SOL_AMOUNT: u64 = 85000000000; //85 SOL
 
 #[account]
 #[derive(InitSpace)]
 pub struct GlobalConfig {
     pub admin: Pubkey, //also authority to withdraw..
-    pub fee_recipient: Pubkey,
+    pub fee_recipient: Pubkey, // unused, platform fees go to the platform fee vault
     pub fee_rate: u32,
     pub bump: u8,
     pub is_initialized: bool,
+    // fields below are appended to the original layout, older configs get them from migrate_global_config
+    // default creator share of every trade, tokens may override it up to max_creator_fee_bps
+    pub creator_fee_bps: u16,
+    pub max_creator_fee_bps: u16,
+    // referrer share of the platform fee
+    pub referral_fee_bps: u16,
+    // flat amounts taken from the raised SOL when a curve completes, in lamports
+    pub graduation_fee: u64,
+    pub creator_completion_bonus: u64,
+    // cumulative platform fee accounting, in lamports
+    pub total_platform_fees: u64, // paid into the platform fee vault, graduation fees included
+    pub total_referral_fees: u64, // paid to referrers instead
+    pub total_platform_fees_claimed: u64,
+    pub withdraw_destination: Pubkey, // receives the migration residue of completed curves
+}
+
+impl GlobalConfig {
+    pub fn claimable_platform_fees(&self) -> u64 {
+        self.total_platform_fees - self.total_platform_fees_claimed
+    }
+
+    pub fn record_fees(&mut self, fees: &TradeFees) -> Result<()> {
+        self.total_platform_fees = self
+            .total_platform_fees
+            .checked_add(fees.platform_fee)
+            .ok_or(PumpFunError::MathOverflow)?;
+        self.total_referral_fees = self
+            .total_referral_fees
+            .checked_add(fees.referral_fee)
+            .ok_or(PumpFunError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn validate_fees(&self) -> Result<()> {
+        require!(
+            self.creator_fee_bps <= self.max_creator_fee_bps
+                && self.max_creator_fee_bps as u64 <= BPS_DENOMINATOR
+                && self.referral_fee_bps as u64 <= BPS_DENOMINATOR
+                && self.fee_rate as u64 <= FEE_RATE_DENOMINATOR,
+            PumpFunError::InvalidFeeBps
+        );
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,68 @@
+use crate::*;
+
+pub const LAUNCH_ALLOCATION_SEED: &[u8] = b"pumpfun_launch_allocation";
+pub const MAX_LAUNCH_WINDOW_SLOTS: u64 = 1_500; // ~10 min
+
+/// Optional anti-sniper settings of a token, buys during the first `slots` slots are capped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LaunchProtection {
+    pub slots: u64,
+    pub max_buy_per_wallet: u64, // tokens
+    pub max_buy_per_tx: u64,     // tokens
+}
+
+impl LaunchProtection {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.slots > 0
+                && self.slots <= MAX_LAUNCH_WINDOW_SLOTS
+                && self.max_buy_per_tx > 0
+                && self.max_buy_per_tx <= self.max_buy_per_wallet,
+            PumpFunError::InvalidLaunchProtection
+        );
+        Ok(())
+    }
+}
+
+/// Tokens one wallet bought during the launch window of one token.
+/// Only created while the window is open.
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchAllocation {
+    pub token_mint: Pubkey,
+    pub user: Pubkey,
+    pub bump: u8,
+    pub bought: u64,
+}
+
+impl LaunchAllocation {
+    // enforces the caps of an open launch window and records the buy, no-op otherwise
+    pub fn check_buy<'info>(
+        curve: &BondingCurve,
+        allocation: &AccountInfo<'info>,
+        bump: u8,
+        user: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let protection = match curve.launch_window(Clock::get()?.slot) {
+            Some(protection) => protection,
+            None => return Ok(()),
+        };
+        require!(amount <= protection.max_buy_per_tx, PumpFunError::LaunchTxCapExceeded);
+
+        if allocation.data_is_empty() {
+            let space = 8 + LaunchAllocation::INIT_SPACE;
+            let signer_seeds: &[&[&[u8]]] =
+                &[&[LAUNCH_ALLOCATION_SEED, curve.token_mint.as_ref(), user.key.as_ref(), &[bump]]];
+            create_pda_account(user, allocation, system_program, space, signer_seeds)?;
+            LaunchAllocation { token_mint: curve.token_mint, user: user.key(), bump, bought: 0 }
+                .try_serialize(&mut &mut allocation.try_borrow_mut_data()?[..])?;
+        }
+
+        let mut state = LaunchAllocation::try_deserialize(&mut &allocation.try_borrow_data()?[..])?;
+        state.bought = state.bought.checked_add(amount).ok_or(PumpFunError::MathOverflow)?;
+        require!(state.bought <= protection.max_buy_per_wallet, PumpFunError::LaunchWalletCapExceeded);
+        state.try_serialize(&mut &mut allocation.try_borrow_mut_data()?[..])
+    }
+}
@@ -0,0 +1,146 @@
+use crate::*;
+
+pub const LIMIT_ORDER_SEED: &[u8] = b"pumpfun_limit_order";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum OrderSide {
+    Buy,  // amount is SOL held by the order account
+    Sell, // amount is tokens held by the order's token account
+}
+
+/// Deposit of one owner waiting for the curve price to cross `trigger_price`.
+/// The order account also holds the executor tip on top of its rent.
+#[account]
+#[derive(InitSpace)]
+pub struct LimitOrder {
+    pub token_mint: Pubkey,
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub bump: u8,
+    pub side: OrderSide,
+    pub amount: u64,
+    pub trigger_price: u64, // lamports per whole token, fees included
+    pub tip: u64,
+}
+
+/// One fill of an order against the curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderFill {
+    pub token_amount: u64,
+    pub curve_sol: u64, // paid into the curve on buys, out of it on sells, fees excluded
+    pub fees: TradeFees,
+}
+
+impl LimitOrder {
+    pub fn new(token_mint: Pubkey, owner: Pubkey, bump: u8, params: &PlaceLimitOrderParams) -> Self {
+        LimitOrder {
+            token_mint,
+            owner,
+            order_id: params.order_id,
+            bump,
+            side: params.side,
+            amount: params.amount,
+            trigger_price: params.trigger_price,
+            tip: params.tip,
+        }
+    }
+
+    // a buy fills at or below the trigger price, a sell at or above it
+    pub fn crossed(&self, sol: u64, tokens: u64, decimals: u8) -> bool {
+        let sol = sol as u128 * 10u128.pow(decimals as u32);
+        let value = tokens as u128 * self.trigger_price as u128;
+        match self.side {
+            OrderSide::Buy => sol <= value,
+            OrderSide::Sell => sol >= value,
+        }
+    }
+
+    // None when the order is not crossed or too small to fill after its fees,
+    // the fees come out of the deposited SOL on buys and out of the proceeds on sells
+    pub fn fill(&self, vault_amount: u64, creator_fee_bps: u16, fee_rate: u32, decimals: u8) -> Result<Option<OrderFill>> {
+        match self.side {
+            OrderSide::Buy => {
+                let fees = TradeFees::new(self.amount, creator_fee_bps, fee_rate, None);
+                // buy_amount needs at least 2 lamports
+                let sol_in = match self.amount.checked_sub(fees.total()) {
+                    Some(sol_in) if sol_in > 1 => sol_in,
+                    _ => return Ok(None),
+                };
+                let token_amount = buy_amount(vault_amount, sol_in, decimals)?;
+                if token_amount > available_supply(vault_amount)? || !self.crossed(self.amount, token_amount, decimals) {
+                    return Ok(None);
+                }
+                Ok(Some(OrderFill { token_amount, curve_sol: sol_in, fees }))
+            }
+            OrderSide::Sell => {
+                // priced on the supply before the tokens come back
+                let sol_amount = sell_proceeds(vault_amount, self.amount, decimals)?;
+                let fees = TradeFees::new(sol_amount, creator_fee_bps, fee_rate, None);
+                match sol_amount.checked_sub(fees.total()) {
+                    Some(sol_output) if self.crossed(sol_output, self.amount, decimals) => {
+                        Ok(Some(OrderFill { token_amount: self.amount, curve_sol: sol_amount, fees }))
+                    }
+                    _ => Ok(None),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(side: OrderSide, amount: u64, trigger_price: u64) -> LimitOrder {
+        let params = PlaceLimitOrderParams { order_id: 0, side, amount, trigger_price, tip: 0 };
+        LimitOrder::new(Pubkey::default(), Pubkey::default(), 0, &params)
+    }
+
+    #[test]
+    fn test_crossed() {
+        let decimals = 6;
+        // 2 whole tokens for 20_000 lamports is 10_000 per token
+        assert!(order(OrderSide::Buy, 0, 10_000).crossed(20_000, 2_000_000, decimals));
+        assert!(!order(OrderSide::Buy, 0, 9_999).crossed(20_000, 2_000_000, decimals));
+        assert!(order(OrderSide::Sell, 0, 10_000).crossed(20_000, 2_000_000, decimals));
+        assert!(!order(OrderSide::Sell, 0, 10_001).crossed(20_000, 2_000_000, decimals));
+    }
+
+    #[test]
+    fn test_fill() {
+        let decimals = 9;
+        let buy = order(OrderSide::Buy, 1_000_000_000, u64::MAX);
+        let fill = buy.fill(MAX_SUPPLY, 100, 1_000, decimals).unwrap().unwrap();
+        assert_eq!(fill.fees, TradeFees::new(1_000_000_000, 100, 1_000, None));
+        assert_eq!(fill.curve_sol, 1_000_000_000 - fill.fees.total());
+        assert_eq!(fill.token_amount, buy_amount(MAX_SUPPLY, fill.curve_sol, decimals).unwrap());
+        // not crossed at a trigger price below the curve price
+        assert_eq!(order(OrderSide::Buy, 1_000_000_000, 1).fill(MAX_SUPPLY, 100, 1_000, decimals).unwrap(), None);
+
+        let vault_amount = MAX_SUPPLY - fill.token_amount;
+        let sell = order(OrderSide::Sell, fill.token_amount / 2, 1);
+        let fill = sell.fill(vault_amount, 100, 1_000, decimals).unwrap().unwrap();
+        assert_eq!(fill.curve_sol, sell_proceeds(vault_amount, sell.amount, decimals).unwrap());
+        assert_eq!(fill.fees, TradeFees::new(fill.curve_sol, 100, 1_000, None));
+        assert_eq!(order(OrderSide::Sell, sell.amount, u64::MAX).fill(vault_amount, 100, 1_000, decimals).unwrap(), None);
+    }
+
+    #[test]
+    fn test_dust_order_skipped_in_batch() {
+        let decimals = 9;
+        let batch = [
+            order(OrderSide::Buy, 1_000_000_000, u64::MAX),
+            order(OrderSide::Buy, 1, u64::MAX), // nothing left for the curve after fees
+            order(OrderSide::Buy, 500_000_000, u64::MAX),
+        ];
+        let mut vault_amount = MAX_SUPPLY;
+        let mut filled = Vec::new();
+        for order in &batch {
+            if let Some(fill) = order.fill(vault_amount, 100, 1_000, decimals).unwrap() {
+                vault_amount -= fill.token_amount;
+                filled.push(order.amount);
+            }
+        }
+        assert_eq!(filled, [1_000_000_000, 500_000_000]);
+    }
+}
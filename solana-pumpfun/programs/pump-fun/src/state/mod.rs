@@ -1,7 +1,29 @@
 pub mod global_config;
+pub mod bonding_curve;
 pub mod my_oapp;
 pub mod peer;
+pub mod oft_store;
+pub mod peer_sol_vault;
+pub mod cross_chain_log;
+pub mod creator_vault;
+pub mod referrer;
+pub mod platform_fee_vault;
+pub mod launch_allocation;
+pub mod presale_claim;
+pub mod limit_order;
+pub mod dca_vault;
 
 pub use global_config::*;
+pub use bonding_curve::*;
 pub use my_oapp::*;
 pub use peer::*;
+pub use oft_store::*;
+pub use peer_sol_vault::*;
+pub use cross_chain_log::*;
+pub use creator_vault::*;
+pub use referrer::*;
+pub use platform_fee_vault::*;
+pub use launch_allocation::*;
+pub use presale_claim::*;
+pub use limit_order::*;
+pub use dca_vault::*;
@@ -0,0 +1,31 @@
+use crate::*;
+
+// lock on send, unlock on receive from the token_escrow; meme mints have no mint authority
+// left after creation, so there is no mint/burn mode
+#[account]
+#[derive(InitSpace)]
+pub struct OftStore {
+    // immutable
+    pub ld2sd_rate: u64,
+    pub token_mint: Pubkey,
+    pub token_escrow: Pubkey, // token account owned by the oft_store
+    pub bump: u8,
+    // mutable
+    pub tvl_ld: u64, // total value locked in the token_escrow
+}
+
+impl OftStore {
+    pub fn ld2sd(&self, amount_ld: u64) -> u64 {
+        amount_ld / self.ld2sd_rate
+    }
+
+    pub fn sd2ld(&self, amount_sd: u64) -> Result<u64> {
+        amount_sd
+            .checked_mul(self.ld2sd_rate)
+            .ok_or(error!(OftError::InvalidDecimals))
+    }
+
+    pub fn remove_dust(&self, amount_ld: u64) -> u64 {
+        amount_ld - amount_ld % self.ld2sd_rate
+    }
+}
@@ -35,4 +35,11 @@ impl EnforcedOptions {
 pub struct Peer {
     pub address: [u8; 32],
     pub bump: u8,
+    // messages from the peer (lz_receive)
+    pub inbound_enabled: bool,
+    // messages to the peer (send, quote)
+    pub outbound_enabled: bool,
+    // send_token to the peer, off until the remote side credits and returns OFT msgs
+    // (the evm TokenFactory ignores msg type 3)
+    pub token_transfers_enabled: bool,
 }
@@ -0,0 +1,45 @@
+use crate::*;
+
+pub const PEER_SOL_VAULT_SEED: &[u8] = b"PeerSolVault";
+
+/// SOL liquidity backing inbound buys from one peer.
+/// Operators fund it against the payments collected on the remote chain,
+/// inbound buys and executor reimbursements are paid out of it.
+#[account]
+#[derive(InitSpace)]
+pub struct PeerSolVault {
+    pub eid: u32,
+    pub bump: u8,
+    pub total_funded: u64,
+    pub total_spent: u64,          // paid into bonding curves
+    pub total_rent_reimbursed: u64, // token account rent paid back to executors
+    pub total_withdrawn: u64,
+    pub remote_settled: u64,       // amount collected on the remote chain, as last reconciled
+}
+
+impl PeerSolVault {
+    // lamports that can be paid out without touching the rent-exempt minimum
+    pub fn available(&self, info: &AccountInfo, rent: &Rent) -> u64 {
+        info.lamports()
+            .saturating_sub(rent.minimum_balance(info.data_len()))
+    }
+
+    pub fn record_funded(&mut self, amount: u64) -> Result<()> {
+        self.total_funded = self.total_funded.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_spent(&mut self, sol_amount: u64, rent_reimbursed: u64) -> Result<()> {
+        self.total_spent = self.total_spent.checked_add(sol_amount).ok_or(ProgramError::ArithmeticOverflow)?;
+        self.total_rent_reimbursed = self
+            .total_rent_reimbursed
+            .checked_add(rent_reimbursed)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_withdrawn(&mut self, amount: u64) -> Result<()> {
+        self.total_withdrawn = self.total_withdrawn.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
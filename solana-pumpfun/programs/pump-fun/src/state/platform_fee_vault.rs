@@ -0,0 +1,11 @@
+use crate::*;
+
+pub const PLATFORM_FEE_VAULT_SEED: &[u8] = b"pumpfun_fee_vault";
+
+/// Collects the platform fee of every trade, emptied by the admin with claim_platform_fees.
+/// Accounting lives in GlobalConfig.
+#[account]
+#[derive(InitSpace)]
+pub struct PlatformFeeVault {
+    pub bump: u8,
+}
@@ -0,0 +1,13 @@
+use crate::*;
+
+pub const PRESALE_CLAIM_SEED: &[u8] = b"pumpfun_presale_claim";
+
+/// Tokens one allowlisted wallet bought in the presale of one token.
+#[account]
+#[derive(InitSpace)]
+pub struct PresaleClaim {
+    pub token_mint: Pubkey,
+    pub user: Pubkey,
+    pub bump: u8,
+    pub bought: u64,
+}
@@ -0,0 +1,20 @@
+use crate::*;
+
+pub const REFERRER_SEED: &[u8] = b"pumpfun_referrer";
+
+/// Registered referrer, holds its share of the platform fee until claimed.
+#[account]
+#[derive(InitSpace)]
+pub struct Referrer {
+    pub authority: Pubkey,
+    pub bump: u8,
+    pub total_accrued: u64,
+    pub total_claimed: u64,
+    pub trade_count: u64,
+}
+
+impl Referrer {
+    pub fn claimable(&self) -> u64 {
+        self.total_accrued - self.total_claimed
+    }
+}
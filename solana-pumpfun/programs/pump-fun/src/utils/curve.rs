@@ -0,0 +1,104 @@
+use crate::*;
+
+// checked curve accounting on top of cost.rs, every rounding goes in the curve's favour
+
+// tokens out of the vault, MAX_SUPPLY is minted to the vault at creation
+pub fn tokens_sold(vault_amount: u64) -> Result<u64> {
+    MAX_SUPPLY
+        .checked_sub(vault_amount)
+        .ok_or(error!(PumpFunError::CurveInvariantViolated))
+}
+
+// tokens still for sale before the curve completes
+pub fn available_supply(vault_amount: u64) -> Result<u64> {
+    match vault_amount.checked_sub(INIT_SUPPLY) {
+        Some(available) if available > 0 => Ok(available),
+        _ => err!(PumpFunError::AlreadyRaised),
+    }
+}
+
+// SOL paid into the curve for `amount` tokens, one lamport above the truncated cost
+pub fn buy_cost(vault_amount: u64, amount: u64, decimals: u8) -> Result<u64> {
+    require!(amount > 0, PumpFunError::InvalidInput);
+    require!(amount <= available_supply(vault_amount)?, PumpFunError::NotEnoughSuppply);
+    calculate_cost(tokens_sold(vault_amount)?, amount, decimals)
+        .checked_add(1)
+        .ok_or(error!(PumpFunError::MathOverflow))
+}
+
+// tokens out of the curve for `sol` lamports paid into it
+pub fn buy_amount(vault_amount: u64, sol: u64, decimals: u8) -> Result<u64> {
+    require!(sol > 1, PumpFunError::InvalidInput);
+    available_supply(vault_amount)?;
+    Ok(calculate_token_amount(tokens_sold(vault_amount)?, sol - 1, decimals))
+}
+
+// SOL paid out of the curve for `amount` tokens sold back, truncated
+pub fn sell_proceeds(vault_amount: u64, amount: u64, decimals: u8) -> Result<u64> {
+    require!(amount > 0, PumpFunError::InvalidInput);
+    let sold = tokens_sold(vault_amount)?;
+    let supply_after = sold
+        .checked_sub(amount)
+        .ok_or(error!(PumpFunError::NotEnoughSuppply))?;
+    Ok(calculate_cost(supply_after, amount, decimals))
+}
+
+// tokens per lamport at least min_tokens per lamports, slippage check that scales with partial fills
+pub fn price_at_least(tokens: u64, lamports: u64, min_tokens: u64, per_lamports: u64) -> bool {
+    tokens as u128 * per_lamports as u128 >= min_tokens as u128 * lamports as u128
+}
+
+// SOL the curve must hold to buy back every sold token, one lamport of float tolerance
+pub fn implied_reserve(tokens_sold: u64, decimals: u8) -> u64 {
+    calculate_cost(0, tokens_sold, decimals).saturating_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::curve::*;
+
+    #[test]
+    fn test_supply() {
+        assert_eq!(tokens_sold(MAX_SUPPLY).unwrap(), 0);
+        assert!(tokens_sold(MAX_SUPPLY + 1).is_err());
+        assert_eq!(available_supply(MAX_SUPPLY).unwrap(), MAX_SUPPLY - INIT_SUPPLY);
+        assert!(available_supply(INIT_SUPPLY).is_err());
+        assert!(available_supply(0).is_err());
+    }
+
+    #[test]
+    fn test_round_trip_keeps_reserve() {
+        let decimals = 9;
+        let mut vault_amount = MAX_SUPPLY;
+        let mut reserve = 0u64;
+        for amount in [1_000_000_000u64, 123_456_789_000, 50_000_000_000_000] {
+            reserve += buy_cost(vault_amount, amount, decimals).unwrap();
+            vault_amount -= amount;
+            assert!(reserve >= implied_reserve(tokens_sold(vault_amount).unwrap(), decimals));
+        }
+        for amount in [50_000_000_000_000u64, 1_000_000_000] {
+            reserve -= sell_proceeds(vault_amount, amount, decimals).unwrap();
+            vault_amount += amount;
+            assert!(reserve >= implied_reserve(tokens_sold(vault_amount).unwrap(), decimals));
+        }
+        assert!(sell_proceeds(vault_amount, tokens_sold(vault_amount).unwrap() + 1, decimals).is_err());
+    }
+
+    #[test]
+    fn test_price_at_least() {
+        // full fill: plain min out / max cost
+        assert!(price_at_least(100, 1_000, 100, 1_000));
+        assert!(!price_at_least(99, 1_000, 100, 1_000));
+        // half filled for half the SOL keeps the price
+        assert!(price_at_least(50, 500, 100, 1_000));
+        assert!(!price_at_least(50, 600, 100, 1_000));
+    }
+
+    #[test]
+    fn test_buy_amount_within_paid() {
+        let decimals = 9;
+        let sol = 1_000_000_000; // 1 SOL
+        let amount = buy_amount(MAX_SUPPLY, sol, decimals).unwrap();
+        assert!(calculate_cost(0, amount, decimals) <= sol);
+    }
+}
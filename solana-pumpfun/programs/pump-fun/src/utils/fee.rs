@@ -0,0 +1,184 @@
+use crate::*;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+// GlobalConfig::fee_rate unit, 1: 0.001 %
+pub const FEE_RATE_DENOMINATOR: u64 = 100_000;
+
+// fee in lamports for an amount at the given basis points, rounded down
+pub fn calculate_fee(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+pub fn calculate_platform_fee(amount: u64, fee_rate: u32) -> u64 {
+    (amount as u128 * fee_rate as u128 / FEE_RATE_DENOMINATOR as u128) as u64
+}
+
+/// Fees of one trade, all in lamports.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TradeFees {
+    pub creator_fee: u64,
+    pub platform_fee: u64, // after the referral share
+    pub referral_fee: u64, // share of the platform fee, 0 without a referrer
+}
+
+impl TradeFees {
+    pub fn new(amount: u64, creator_fee_bps: u16, fee_rate: u32, referral_fee_bps: Option<u16>) -> Self {
+        let platform_fee = calculate_platform_fee(amount, fee_rate);
+        let referral_fee = referral_fee_bps.map_or(0, |bps| calculate_fee(platform_fee, bps));
+        TradeFees {
+            creator_fee: calculate_fee(amount, creator_fee_bps),
+            platform_fee: platform_fee - referral_fee,
+            referral_fee,
+        }
+    }
+
+    pub fn total(&self) -> u64 {
+        self.creator_fee + self.platform_fee + self.referral_fee
+    }
+}
+
+// smallest amount that still leaves `net` once its fees are taken out,
+// the referral share comes out of the platform fee and does not change the total.
+// None when the fees take the whole amount
+pub fn gross_up(net: u64, creator_fee_bps: u16, fee_rate: u32) -> Option<u64> {
+    let net_of = |gross: u64| gross - TradeFees::new(gross, creator_fee_bps, fee_rate, None).total();
+    let fee_units = creator_fee_bps as u64 * (FEE_RATE_DENOMINATOR / BPS_DENOMINATOR) + fee_rate as u64;
+    let remaining_units = FEE_RATE_DENOMINATOR.checked_sub(fee_units).filter(|units| *units > 0)?;
+    // fees round down, so this is at most a few lamports above the smallest amount
+    let estimate = (net as u128 * FEE_RATE_DENOMINATOR as u128).div_ceil(remaining_units as u128);
+    let mut gross = u64::try_from(estimate).ok()?;
+    while gross > net && net_of(gross - 1) >= net {
+        gross -= 1;
+    }
+    Some(gross)
+}
+
+// moves the fees of one trade out of `from` and books them,
+// program owned accounts (curves, orders, dca vaults) pay by a direct lamport move, users by a system transfer.
+// without a creator vault the creator fee stays with the caller (unswept on the curve)
+pub fn pay_trade_fees<'info>(
+    from: &AccountInfo<'info>,
+    fees: &TradeFees,
+    creator_vault: Option<&mut Account<'info, CreatorVault>>,
+    platform_fee_vault: &AccountInfo<'info>,
+    referrer: Option<&mut Account<'info, Referrer>>,
+    global_config: &mut GlobalConfig,
+) -> Result<()> {
+    let pay = |to: AccountInfo<'info>, lamports: u64| {
+        if from.owner == &crate::ID {
+            transfer_sol_from_vault_to_user(from.clone(), to, lamports)
+        } else {
+            transfer_sol(from.clone(), to, lamports)
+        }
+    };
+    if let Some(creator_vault) = creator_vault {
+        if fees.creator_fee > 0 {
+            pay(creator_vault.to_account_info(), fees.creator_fee)?;
+            creator_vault.total_accrued = creator_vault
+                .total_accrued
+                .checked_add(fees.creator_fee)
+                .ok_or(PumpFunError::MathOverflow)?;
+        }
+    }
+    if fees.platform_fee > 0 {
+        pay(platform_fee_vault.clone(), fees.platform_fee)?;
+    }
+    if let Some(referrer) = referrer {
+        pay(referrer.to_account_info(), fees.referral_fee)?;
+        referrer.total_accrued = referrer
+            .total_accrued
+            .checked_add(fees.referral_fee)
+            .ok_or(PumpFunError::MathOverflow)?;
+        referrer.trade_count = referrer.trade_count.checked_add(1).ok_or(PumpFunError::MathOverflow)?;
+    }
+    global_config.record_fees(fees)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::fee::*;
+    use crate::*;
+
+    #[test]
+    fn test_fee() {
+        assert_eq!(calculate_fee(1_000_000_000, 0), 0);
+        assert_eq!(calculate_fee(1_000_000_000, 100), 10_000_000); // 1%
+        assert_eq!(calculate_fee(999, 100), 9); // rounded down
+        assert_eq!(calculate_fee(u64::MAX, 10_000), u64::MAX);
+        assert_eq!(calculate_platform_fee(1_000_000_000, 1_000), 10_000_000); // 1%
+    }
+
+    #[test]
+    fn test_trade_fees() {
+        let fees = TradeFees::new(1_000_000_000, 100, 1_000, None);
+        assert_eq!(fees, TradeFees { creator_fee: 10_000_000, platform_fee: 10_000_000, referral_fee: 0 });
+
+        // referrer takes 30% of the platform fee
+        let fees = TradeFees::new(1_000_000_000, 100, 1_000, Some(3_000));
+        assert_eq!(fees, TradeFees { creator_fee: 10_000_000, platform_fee: 7_000_000, referral_fee: 3_000_000 });
+        assert_eq!(fees.total(), 20_000_000);
+    }
+
+    #[test]
+    fn test_gross_up() {
+        let cases = [
+            (0, 100, 1_000),
+            (1, 100, 1_000),
+            (999_999_999, 100, 1_000),
+            (12_345, 250, 333),
+            (7, 0, 0),
+        ];
+        for (net, creator_fee_bps, fee_rate) in cases {
+            let gross = gross_up(net, creator_fee_bps, fee_rate).unwrap();
+            let fees = TradeFees::new(gross, creator_fee_bps, fee_rate, None);
+            assert!(gross - fees.total() >= net);
+            if gross > 0 {
+                let fees = TradeFees::new(gross - 1, creator_fee_bps, fee_rate, None);
+                assert!(gross - 1 - fees.total() < net);
+            }
+        }
+        assert_eq!(gross_up(1, 10_000, 0), None);
+        assert_eq!(gross_up(1, 5_000, 50_000), None);
+    }
+
+    fn account_data<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_pay_trade_fees() {
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut lamports = [1_000_000_000u64, 0, 0, 0];
+        let [from_lamports, creator_lamports, platform_lamports, referrer_lamports] = &mut lamports;
+        let mut creator_data = account_data(&CreatorVault { creator: keys[1], bump: 0, total_accrued: 0, total_claimed: 0 });
+        let mut referrer_data = account_data(&Referrer { authority: keys[3], bump: 0, total_accrued: 0, total_claimed: 0, trade_count: 0 });
+        let (mut from_data, mut platform_data) = (Vec::new(), Vec::new());
+
+        // a curve pays, so the lamports move without a system transfer
+        let from = AccountInfo::new(&keys[0], false, true, from_lamports, &mut from_data, &crate::ID, false, 0);
+        let creator_info = AccountInfo::new(&keys[1], false, true, creator_lamports, &mut creator_data, &crate::ID, false, 0);
+        let platform_fee_vault = AccountInfo::new(&keys[2], false, true, platform_lamports, &mut platform_data, &crate::ID, false, 0);
+        let referrer_info = AccountInfo::new(&keys[3], false, true, referrer_lamports, &mut referrer_data, &crate::ID, false, 0);
+        let mut creator_vault = Account::<CreatorVault>::try_from(&creator_info).unwrap();
+        let mut referrer = Account::<Referrer>::try_from(&referrer_info).unwrap();
+        let mut global_config = GlobalConfig::deserialize(&mut &[0u8; 1024][..]).unwrap();
+
+        let fees = TradeFees::new(1_000_000_000, 100, 1_000, Some(3_000));
+        pay_trade_fees(&from, &fees, Some(&mut creator_vault), &platform_fee_vault, Some(&mut referrer), &mut global_config).unwrap();
+        assert_eq!(from.lamports(), 1_000_000_000 - fees.total());
+        assert_eq!(creator_info.lamports(), fees.creator_fee);
+        assert_eq!(platform_fee_vault.lamports(), fees.platform_fee);
+        assert_eq!(referrer_info.lamports(), fees.referral_fee);
+        assert_eq!(creator_vault.total_accrued, fees.creator_fee);
+        assert_eq!((referrer.total_accrued, referrer.trade_count), (fees.referral_fee, 1));
+        assert_eq!(global_config.total_platform_fees, fees.platform_fee);
+        assert_eq!(global_config.total_referral_fees, fees.referral_fee);
+
+        // without a creator vault the creator fee stays with the payer
+        pay_trade_fees(&from, &fees, None, &platform_fee_vault, None, &mut global_config).unwrap();
+        assert_eq!(from.lamports(), 1_000_000_000 - fees.total() - fees.platform_fee);
+        assert_eq!(creator_vault.total_accrued, fees.creator_fee);
+    }
+}
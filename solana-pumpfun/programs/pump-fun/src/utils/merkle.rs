@@ -0,0 +1,42 @@
+use anchor_lang::{prelude::Pubkey, solana_program::keccak::hashv};
+
+// allowlist leaf: keccak(wallet || allocation as little endian u64)
+pub fn presale_leaf(wallet: &Pubkey, allocation: u64) -> [u8; 32] {
+    hashv(&[wallet.as_ref(), &allocation.to_le_bytes()]).0
+}
+
+// sorted-pair keccak tree, so proofs carry no left/right flags
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).0
+        } else {
+            hashv(&[sibling, &node]).0
+        }
+    });
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::merkle::*;
+
+    fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b { hashv(&[&a, &b]).0 } else { hashv(&[&b, &a]).0 }
+    }
+
+    #[test]
+    fn test_verify_proof() {
+        let wallets = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let leaves: Vec<[u8; 32]> = wallets.iter().map(|w| presale_leaf(w, 1_000)).collect();
+        let left = parent(leaves[0], leaves[1]);
+        let root = parent(left, leaves[2]);
+
+        assert!(verify_proof(&[leaves[1], leaves[2]], &root, leaves[0]));
+        assert!(verify_proof(&[leaves[0], leaves[2]], &root, leaves[1]));
+        assert!(verify_proof(&[left], &root, leaves[2]));
+        // wrong allocation or wallet
+        assert!(!verify_proof(&[leaves[1], leaves[2]], &root, presale_leaf(&wallets[0], 2_000)));
+        assert!(!verify_proof(&[left], &root, presale_leaf(&Pubkey::new_unique(), 1_000)));
+    }
+}
@@ -6,3 +6,12 @@ pub use cost::*;
 
 pub mod calc;
 pub use calc::*;
+
+pub mod fee;
+pub use fee::*;
+
+pub mod curve;
+pub use curve::*;
+
+pub mod merkle;
+pub use merkle::*;
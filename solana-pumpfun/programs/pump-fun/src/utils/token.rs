@@ -1,6 +1,9 @@
 use anchor_lang::{
     prelude::*,
     solana_program::{program::invoke, system_instruction},
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
 };
 
 use anchor_spl::token_2022;
@@ -18,6 +21,56 @@ pub fn transfer_sol<'info>(
     Ok(())
 }
 
+// creates a program owned PDA, also when someone sent lamports to the address first
+// (create_account fails on an account that already holds lamports)
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+    if current_lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: account.clone() },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+    if current_lamports < rent {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            rent - current_lamports,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate { account_to_allocate: account.clone() },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign { account_to_assign: account.clone() },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
+
 pub fn transfer_sol_from_vault_to_user<'info>(
     sender: AccountInfo<'info>,
     to: AccountInfo<'info>,
@@ -84,3 +137,23 @@ pub fn transfer_token_from_vault_to_user<'info>(
         decimals,
     )
 }
+
+pub fn close_token_account_from_vault<'info>(
+    authority: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    token_2022::close_account(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_2022::CloseAccount {
+                account,
+                destination,
+                authority,
+            },
+        )
+        .with_signer(signer_seeds),
+    )
+}
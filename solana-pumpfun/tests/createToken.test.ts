@@ -45,7 +45,7 @@ describe("CreateToken", () => {
       // configPk,
       "AAA",
       "AAA",
-      "URI123456789"
+      "https://example.com/aaa.json"
     )
 
     console.log('payer:', payer.publicKey.toBase58());
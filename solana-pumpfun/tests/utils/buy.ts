@@ -16,7 +16,8 @@ export const buy = async (
 ) => {
     const tx = await program.methods.buy(
       buyAmount,
-      maxSolAmount
+      maxSolAmount,
+      false
     ).accounts({
       tokenMint,
       mintAuthority,
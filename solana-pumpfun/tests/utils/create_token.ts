@@ -1,12 +1,8 @@
-import { Program, web3 } from "@coral-xyz/anchor";
-import { createMint, getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID,
-  createAssociatedTokenAccount,
-  createAssociatedTokenAccountInstruction
- } from "@solana/spl-token";
+import { Program, web3, BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { MPL_TOKEN_METADATA_PROGRAM_ID  } from "@metaplex-foundation/mpl-token-metadata";
-import { PublicKey, SystemProgram, Transaction, sendAndConfirmTransaction } from "@solana/web3.js";
+import { PublicKey } from "@solana/web3.js";
 import { PumpFun } from "../../target/types/pump_fun";
-import { OftTools } from '@layerzerolabs/lz-solana-sdk-v2'
 export const createToken = async (
   program: Program<PumpFun>,
   payer: web3.Keypair,
@@ -59,98 +55,61 @@ export const createToken = async (
     metadata_program_id
   );
 
-  const [ oftConfig ] = PublicKey.findProgramAddressSync(
+  const [ globalConfig ] = PublicKey.findProgramAddressSync(
     [
-      Buffer.from("Oft"),
-      tokenMintKP.publicKey.toBuffer()
+      Buffer.from("pumpfun_config")
     ],
     program.programId
   )
 
-  const [ lzReceiveTypesAccounts ] = PublicKey.findProgramAddressSync(
+  const [ creatorVault ] = PublicKey.findProgramAddressSync(
     [
-      Buffer.from("LzReceiveTypes"),
-      oftConfig.toBuffer()
+      Buffer.from("pumpfun_creator_vault"),
+      payer.publicKey.toBuffer()
     ],
     program.programId
   )
-  const endpointProgram = new PublicKey("76y77prsiCMvXMjuoZ5VRrhG5qYBrUMYTE5WgHqgjEn6")
-
-  //create token mint
-  console.log('------create-token: tx0')
-  const tx0 = await createMint(program.provider.connection, payer, oftConfig, null, 9, tokenMintKP)
-
-  //create bonding_curve
-  // console.log('------create bonding-curve')
-  // const lamports = await program.provider.connection.getMinimumBalanceForRentExemption(0);
-  // const transaction = new Transaction().add(
-  //   SystemProgram.createAccount({
-  //     fromPubkey:payer.publicKey,
-  //     lamports,
-  //     newAccountPubkey: bondingCurve,
-  //     space: 0,
-  //     programId: program.programId
-  //   })
-  // )
-  // await sendAndConfirmTransaction(program.provider.connection, transaction,[payer])
-  //associated boding curve
-  console.log('----------Create associated bonding curve')
-
-  const associatedToken = getAssociatedTokenAddressSync(tokenMintKP.publicKey, bondingCurve, true)
-
-  const transaction = new Transaction().add(
-      createAssociatedTokenAccountInstruction(
-          payer.publicKey,
-          associatedToken,
-          bondingCurve,
-          tokenMintKP.publicKey,
-      )
-  );
-
-  await sendAndConfirmTransaction(program.provider.connection, transaction, [payer])
-
-  console.log('--------associted_user_token_account')
-  await createAssociatedTokenAccount(program.provider.connection, payer, tokenMintKP.publicKey, payer.publicKey)
 
+  const [ platformFeeVault ] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("pumpfun_fee_vault")
+    ],
+    program.programId
+  )
 
-  const [oappRegistry] =  PublicKey.findProgramAddressSync([Buffer.from("OApp", "utf8"), oftConfig.toBuffer()], endpointProgram)
-  const [ eventAuthority] = PublicKey.findProgramAddressSync([Buffer.from("__event_authority", "utf8")], endpointProgram)
+  // the program creates the mint, the curve ATA and the creator ATA
   console.log('-------createMemeToken')
   const tx = await program.methods.createToken(
     {
-      name: Buffer.from(tokenName),
-      symbol: Buffer.from(tokenSymbol),
-      uri: Buffer.from(tokenUri),
-      endpointProgram
+      name: tokenName,
+      symbol: tokenSymbol,
+      uri: tokenUri,
+      initialBuySol: null,
+      initialBuyTokens: null,
+      initialBuyLimit: new BN(0),
+      metadataAuthority: { immutable: {} },
+      creatorFeeBps: null,
+      launchProtection: null,
+      tradingStartTs: null,
+      presaleMerkleRoot: null,
+      maxHoldingBps: null,
     }
   ).accounts({
     payer: payer.publicKey,
     tokenMint: tokenMintKP.publicKey,
-    oftConfig,
-    // lzReceiveTypesAccounts,
+    globalConfig,
     bondingCurve,
+    creatorVault,
+    platformFeeVault,
     associtedBondingCurve,
-    // associtedUserTokenAccount,
+    associtedUserTokenAccount,
     metadata: metadataPDA,
     associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
     tokenProgram: TOKEN_PROGRAM_ID,
     tokenMetadataProgram: metadata_program_id,
     rent: web3.SYSVAR_RENT_PUBKEY,
     systemProgram: web3.SystemProgram.programId,
-  }).signers([payer]).rpc()
-
-  console.log('-----Create OFT----------')
-  const initOftIx = await OftTools.createInitNativeOftIx(
-    program.programId,
-    payer.publicKey,
-    payer.publicKey,
-    tokenMintKP.publicKey,
-    oftConfig
-  )
-  const transaction1 = new Transaction()
-  transaction1.add(initOftIx)
-  await sendAndConfirmTransaction(program.provider.connection, transaction1, [payer])
-
+  }).signers([payer, tokenMintKP]).rpc()
 
   console.log(tx)
   return {
@@ -8,14 +8,14 @@ export const withdraw = async (
     tokenMint: PublicKey,
     bondingCurve: PublicKey,
     associtedBondingCurve: PublicKey,
-    associtedUserTokenAccount: PublicKey,
+    destinationTokenAccount: PublicKey,
 ) => {    
     const tx = await program.methods.withdraw().accounts({
       authority: payer.publicKey,
       tokenMint, 
       bondingCurve,
       associtedBondingCurve,
-      associtedUserTokenAccount, //receive
+      destinationTokenAccount, //receive
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       tokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId
@@ -59,19 +59,26 @@ describe("WithdrawToken", () => {
       associtedBondingCurve,
       associtedUserTokenAccount.address
     )
-    await withdraw(
-      program,
-      payer,
-      tokenMint,
-      bondingCurve,
-      associtedBondingCurve,
-      associtedUserTokenAccount.address
-    )
+    // the curve is still active, nothing can be withdrawn
+    let failed = false
+    try {
+      await withdraw(
+        program,
+        payer,
+        tokenMint,
+        bondingCurve,
+        associtedBondingCurve,
+        associtedUserTokenAccount.address
+      )
+    } catch (e) {
+      failed = true
+    }
+    assert(failed)
 
     const bondingCurveInfo = await connection.getAccountInfo(bondingCurve)
-    assert(bondingCurveInfo == null) // empty lamports.
+    assert(bondingCurveInfo != null)
     const associtedBondingInfo = await getAccount(connection, associtedBondingCurve)
-    assert(associtedBondingInfo.amount === BigInt(0))
+    assert(associtedBondingInfo.amount > BigInt(0))
 
   })
 })
\ No newline at end of file